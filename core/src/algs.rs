@@ -1,28 +1,29 @@
 //! Core solver functionality shared between different algorithms
 
 use std::{
-    io,
+    cmp, io,
     marker::PhantomData,
     ops::{Not, Range},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 #[cfg(feature = "interrupt-oracle")]
 use std::sync::Mutex;
 
 use anyhow::Context;
-use cadical_veripb_tracer::CadicalCertCollector;
+use cpu_time::ProcessTime;
 use maxpre::MaxPre;
 use rustsat::{
-    encodings::{card::DbTotalizer, pb::DbGte},
+    encodings::{card, pb, CollectClauses},
     instances::{Cnf, ManageVars},
     solvers::{
         DefaultInitializer, Initialize, SolveIncremental, SolveStats, SolverResult, SolverStats,
     },
-    types::{Assignment, Clause, Lit, TernaryVal, WLitIter},
+    types::{Assignment, Clause, Lit, TernaryVal, Var, WLitIter},
 };
 use scuttle_proc::oracle_bounds;
 
@@ -30,20 +31,30 @@ use scuttle_proc::oracle_bounds;
 use maxpre::PreproClauses;
 
 use crate::{
+    checkpoint::{AlgState, Progress},
+    encodings::CertEncoding,
+    logging::{LogFilter, LoggerHandle, MultiLogger},
     options::{CoreBoostingOptions, EnumOptions},
-    types::{Instance, NonDomPoint, ObjEncoding, Objective, ParetoFront, VarManager},
+    types::{
+        Constraint, FrontConnection, Instance, NonDomPoint, ObjEncoding, Objective,
+        ParetoApproximation, ParetoFront, SharedFront, VarManager,
+    },
     EncodingStats, KernelOptions, Limits, MaybeTerminated,
     MaybeTerminatedError::{self, Done, Error, Terminated},
     Phase, Stats, Termination, WriteSolverLog,
 };
 
 pub mod bioptsat;
+pub mod divcon;
+pub mod lexicographic;
 pub mod lowerbounding;
 pub mod pminimal;
 
 mod coreboosting;
 mod coreguided;
+mod oracle;
 mod proofs;
+use oracle::{CertCollector, TracerHandle};
 pub use proofs::{InitCert, InitCertDefaultBlock};
 
 /// Trait for initializing algorithms
@@ -60,7 +71,8 @@ pub trait Init: Sized {
         block_clause_gen: Self::BlockClauseGen,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter;
 
@@ -70,7 +82,8 @@ pub trait Init: Sized {
         opts: KernelOptions,
         block_clause_gen: Self::BlockClauseGen,
     ) -> anyhow::Result<Self> {
-        Self::new(inst.cnf, inst.objs, inst.vm, opts, block_clause_gen)
+        let (constrs, objs, vm) = inst.into_parts();
+        Self::new(constrs, objs, vm, opts, block_clause_gen)
     }
 }
 
@@ -83,7 +96,8 @@ pub trait InitDefaultBlock: Init<BlockClauseGen = fn(Assignment) -> Clause> {
         opts: KernelOptions,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -93,7 +107,8 @@ pub trait InitDefaultBlock: Init<BlockClauseGen = fn(Assignment) -> Clause> {
     /// Initializes the algorithm using an [`Instance`] rather than iterators with the default
     /// blocking clause generator
     fn from_instance_default_blocking(inst: Instance, opts: KernelOptions) -> anyhow::Result<Self> {
-        Self::new(inst.cnf, inst.objs, inst.vm, opts, default_blocking_clause)
+        let (constrs, objs, vm) = inst.into_parts();
+        Self::new(constrs, objs, vm, opts, default_blocking_clause)
     }
 }
 
@@ -103,6 +118,13 @@ impl<Alg> InitDefaultBlock for Alg where Alg: Init<BlockClauseGen = fn(Assignmen
 pub trait Solve: KernelFunctions {
     /// Solves the instance under given limits. If not fully solved, returns an
     /// early termination reason.
+    ///
+    /// After a termination, solving can be resumed by calling this method
+    /// again with fresh limits. The search then continues from the candidate,
+    /// fence or BiOptSat state it was terminated at, so that solving in slices
+    /// finds the same Pareto front as a single run. A non-dominated point at
+    /// which solving was terminated while enumerating solutions is closed with
+    /// the solutions found so far.
     fn solve(&mut self, limits: Limits) -> MaybeTerminatedError;
     /// Gets all statistics from the solver
     fn all_stats(&self) -> (Stats, Option<SolverStats>, Option<Vec<EncodingStats>>);
@@ -111,23 +133,121 @@ pub trait Solve: KernelFunctions {
 /// Core boosting interface
 pub trait CoreBoost {
     /// Performs core boosting. Returns false if instance is unsat.    
-    fn core_boost(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool>;
+    fn core_boost(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+        self.core_boost_with_limits(opts, Limits::none())
+    }
+
+    /// Performs core boosting under the time limits of `limits`, the other
+    /// limits are ignored. Returns false if instance is unsat. The time spent
+    /// core boosting counts towards the time limits of the first call to
+    /// [`Solve::solve`].
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool>;
+}
+
+/// Trait for algorithms that can cooperate with other solvers running in parallel
+pub trait Cooperate {
+    /// Connects the solver to a shared Pareto front. The solver publishes the
+    /// non-dominated points it finds and, between oracle calls, blocks the
+    /// solutions dominated by points published by other solvers. The Pareto
+    /// front of the solver only contains the points it found itself, the
+    /// entire Pareto front is the merge of the fronts of all connected solvers.
+    fn share_front(&mut self, front: &SharedFront) -> anyhow::Result<()>;
+}
+
+/// Trait for algorithms whose solving progress can be checkpointed and restored
+pub trait Checkpoint {
+    /// Gets a snapshot of the current solving progress
+    fn progress(&self) -> Progress;
+    /// Restores the solving progress from a snapshot taken of a solver for the
+    /// same instance with the same algorithm. Must be called before solving.
+    /// Solutions dominated by the restored non-dominated points, as well as
+    /// the algorithm state, are encoded as constraints in the oracle, so that
+    /// the search continues without redoing the proven Pareto points.
+    /// Restoring progress is not supported with proof logging.
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()>;
+    /// Writes a checkpoint of the current solving progress to a file
+    #[cfg(feature = "serde")]
+    fn checkpoint<P: AsRef<std::path::Path>>(&self, path: P) -> anyhow::Result<()> {
+        self.progress().write(path)
+    }
+    /// Restores the solving progress from a checkpoint file, see
+    /// [`Checkpoint::restore_progress`]
+    #[cfg(feature = "serde")]
+    fn restore<P: AsRef<std::path::Path>>(&mut self, path: P) -> anyhow::Result<()> {
+        self.restore_progress(Progress::read(path)?)
+    }
+}
+
+/// Trait for algorithms that can be seeded with known solutions
+pub trait WarmStart {
+    /// Seeds the search with known solutions, e.g., from a heuristic or from
+    /// the Pareto front of an older version of the instance. Every solution
+    /// is checked against the instance and an error is returned if one of
+    /// them is infeasible or does not assign every variable of the original
+    /// instance. Only the values of the variables of the original instance
+    /// are used, the remaining variables are completed by the oracle. The
+    /// relaxation variables of non-unit soft clauses are only set if the
+    /// solution violates the soft clause. Returns the costs of the solutions.
+    fn warm_start<I>(&mut self, solutions: I) -> anyhow::Result<Vec<Vec<isize>>>
+    where
+        I: IntoIterator<Item = Assignment>;
 }
 
 /// Shared functionality provided by the [`Kernel`]
 pub trait KernelFunctions {
     /// Gets the Pareto front discovered so far
     fn pareto_front(&self) -> ParetoFront;
+    /// Gets an approximation of the Pareto front with a quality guarantee,
+    /// including feasible points not proven to be non-dominated yet
+    fn approximation(&self) -> ParetoApproximation;
     /// Gets tracked statistics from the solver
     fn stats(&self) -> Stats;
-    /// Attaches a logger to the solver
-    fn attach_logger<L: WriteSolverLog + 'static>(&mut self, logger: L);
-    /// Detaches a logger from the solver
-    fn detach_logger(&mut self) -> Option<Box<dyn WriteSolverLog>>;
+    /// Attaches a logger to the solver that receives all events. Multiple
+    /// loggers can be attached at the same time.
+    fn attach_logger<L: WriteSolverLog + 'static>(&mut self, logger: L) -> LoggerHandle {
+        self.attach_filtered_logger(logger, LogFilter::all())
+    }
+    /// Attaches a logger to the solver that only receives the events passing
+    /// the filter
+    fn attach_filtered_logger<L: WriteSolverLog + 'static>(
+        &mut self,
+        logger: L,
+        filter: LogFilter,
+    ) -> LoggerHandle;
+    /// Detaches the logger with the given handle from the solver, without
+    /// affecting other attached loggers
+    fn detach_logger(&mut self, handle: LoggerHandle) -> Option<Box<dyn WriteSolverLog>>;
+    /// Sets a callback that is called with every Pareto-optimal solution as
+    /// soon as it is found, together with the non-dominated point it belongs
+    /// to. The point contains all solutions found for it so far. If the
+    /// callback returns [`SolutionAction::Stop`], the search terminates with
+    /// [`Termination::Callback`]. Replaces a previously set callback.
+    fn set_solution_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&NonDomPoint, &Assignment) -> SolutionAction + 'static;
+    /// Removes the solution callback from the solver
+    fn clear_solution_callback(&mut self);
     /// Gets an iterrupter to the solver
     fn interrupter(&mut self) -> Interrupter;
 }
 
+/// The action to take after a solution callback was called
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolutionAction {
+    /// Continue the search
+    Continue,
+    /// Terminate the search early
+    Stop,
+}
+
+/// A callback for Pareto-optimal solutions, see
+/// [`KernelFunctions::set_solution_callback`]
+type SolutionCallback = Box<dyn FnMut(&NonDomPoint, &Assignment) -> SolutionAction>;
+
 pub struct Interrupter {
     /// Termination flag of the solver
     term_flag: Arc<AtomicBool>,
@@ -184,17 +304,40 @@ where
     stats: Stats,
     /// Limits for the current solving run
     lims: Limits,
+    /// Time limit deadlines for the current solving run
+    deadlines: Deadlines,
+    /// The wall-clock and CPU time at which core boosting started, from which
+    /// the time limits of the first solving run count
+    cb_start: Option<(Instant, ProcessTime)>,
     /// An optional inprocessor that has been run at some stage
     inpro: Option<MaxPre>,
-    /// Logger to log with
-    logger: Option<Box<dyn WriteSolverLog>>,
+    /// The cost points found so far for approximating the Pareto front
+    approx: ParetoApproximation,
+    /// The last fence of the lower-bounding algorithm that is known to not
+    /// contain any further non-dominated points
+    exhausted_fence: Option<Vec<usize>>,
+    /// The candidate with its internal costs that the last solving run was
+    /// terminated at, to resume from in the next solving run
+    interrupted_candidate: Option<(Vec<usize>, Assignment)>,
+    /// Known solutions to seed the search with, as their internal costs and
+    /// the literals of the original variables
+    warm_start: Vec<(Vec<usize>, Vec<Lit>)>,
+    /// The connection to a Pareto front shared with other solvers
+    shared_front: Option<FrontConnection>,
+    /// Loggers to log with, [`None`] if no logger is attached
+    logger: Option<MultiLogger>,
+    /// Callback for Pareto-optimal solutions
+    sol_callback: Option<SolutionCallback>,
     /// Termination flag
     term_flag: Arc<AtomicBool>,
     /// The oracle interrupter
     #[cfg(feature = "interrupt-oracle")]
     oracle_interrupter: Arc<Mutex<Box<dyn rustsat::solvers::InterruptSolver + Send>>>,
+    /// The watchdog enforcing the time limits of the current solving run
+    #[cfg(feature = "interrupt-oracle")]
+    watchdog: Option<Watchdog>,
     /// The handle of the proof tracer, when proof logging
-    proof_stuff: Option<proofs::ProofStuff<ProofW>>,
+    proof_stuff: Option<proofs::ProofStuff<O, ProofW>>,
     /// Phantom marker for oracle factory
     _factory: PhantomData<OInit>,
 }
@@ -215,7 +358,8 @@ where
         opts: KernelOptions,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -225,20 +369,26 @@ where
             n_orig_clauses: 0,
             ..Default::default()
         };
+        let mut var_manager = var_manager;
         let mut oracle = OInit::init();
         oracle.reserve(var_manager.max_var().unwrap())?;
         let orig_cnf = if opts.store_cnf {
-            let cnf: Cnf = clauses.into_iter().collect();
-            stats.n_orig_clauses = cnf.len();
+            let mut cnf = Cnf::new();
+            for constr in clauses.into_iter() {
+                stats.n_orig_clauses += 1;
+                add_constraint(constr.into(), &mut cnf, &mut var_manager)?;
+            }
             oracle.add_cnf_ref(&cnf)?;
             Some(cnf)
         } else {
-            for cl in clauses.into_iter() {
+            for constr in clauses.into_iter() {
                 stats.n_orig_clauses += 1;
-                oracle.add_clause(cl)?;
+                add_constraint(constr.into(), &mut oracle, &mut var_manager)?;
             }
             None
         };
+        // constraint encodings are part of the instance encoding
+        var_manager.mark_max_enc_var();
         let objs: Vec<_> = objs
             .into_iter()
             .enumerate()
@@ -305,11 +455,21 @@ where
             opts,
             stats,
             lims: Limits::none(),
+            deadlines: Deadlines::default(),
+            cb_start: None,
             inpro: None,
+            approx: ParetoApproximation::default(),
+            exhausted_fence: None,
+            interrupted_candidate: None,
+            warm_start: vec![],
+            shared_front: None,
             logger: None,
+            sol_callback: None,
             term_flag: Arc::new(AtomicBool::new(false)),
             #[cfg(feature = "interrupt-oracle")]
             oracle_interrupter: Arc::new(Mutex::new(Box::new(interrupter))),
+            #[cfg(feature = "interrupt-oracle")]
+            watchdog: None,
             proof_stuff: None,
             _factory: PhantomData,
         })
@@ -323,14 +483,102 @@ where
     fn start_solving(&mut self, limits: Limits) {
         self.stats.n_solve_calls += 1;
         self.lims = limits;
+        // the time spent core boosting counts towards the first solving run
+        self.deadlines = match self.cb_start.take() {
+            Some((wall, cpu)) => Deadlines::since(&limits, wall, cpu),
+            None => Deadlines::new(&limits),
+        };
+    }
+
+    /// Sets the deadlines of the time limits for core boosting and starts the
+    /// watchdog. The other limits do not apply to core boosting.
+    fn start_core_boosting(&mut self, limits: &Limits) {
+        let (wall, cpu) = (Instant::now(), ProcessTime::now());
+        self.cb_start = Some((wall, cpu));
+        self.deadlines = Deadlines::since(limits, wall, cpu);
+        self.attach_deadlines();
+    }
+
+    /// Records a fence of the lower-bounding algorithm within which all
+    /// non-dominated points have been found
+    fn record_exhausted_fence(&mut self, bounds: Vec<usize>) {
+        debug_assert_eq!(bounds.len(), self.stats.n_objs);
+        self.exhausted_fence = Some(bounds);
+    }
+
+    /// Gets the current approximation of the Pareto front. The lower bound set
+    /// is derived from the objective lower bounds and the last exhausted fence.
+    fn approximation(&self) -> ParetoApproximation {
+        let mut approx = self.approx.clone();
+        let ideal: Vec<_> = self.objs.iter().map(Objective::lower_bound).collect();
+        let lower_bounds = if let Some(fence) = &self.exhausted_fence {
+            // every non-dominated point not found yet exceeds the fence in at
+            // least one objective
+            (0..self.objs.len())
+                .filter(|&idx| !matches!(self.objs[idx], Objective::Constant { .. }))
+                .map(|idx| {
+                    let mut lb = ideal.clone();
+                    lb[idx] = cmp::max(lb[idx], fence[idx] + 1);
+                    self.externalize_internal_costs(&lb)
+                })
+                .collect()
+        } else {
+            vec![self.externalize_internal_costs(&ideal)]
+        };
+        approx.set_lower_bounds(lower_bounds);
+        approx
     }
 
-    fn attach_logger<L: WriteSolverLog + 'static>(&mut self, logger: L) {
-        self.logger = Some(Box::new(logger));
+    /// Takes a snapshot of the solving progress with the given Pareto front
+    /// and algorithm state
+    fn progress(&self, pareto_front: &ParetoFront, state: AlgState) -> Progress {
+        Progress {
+            pareto_front: pareto_front
+                .clone()
+                .convert_solutions(&mut |sol| sol.into_iter().map(Lit::to_ipasir).collect()),
+            lower_bounds: self.objs.iter().map(Objective::lower_bound).collect(),
+            state,
+        }
     }
 
-    fn detach_logger(&mut self) -> Option<Box<dyn WriteSolverLog>> {
-        self.logger.take()
+    /// Connects the kernel to a Pareto front shared with other solvers
+    fn connect_front(&mut self, front: &SharedFront) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.proof_stuff.is_none(),
+            "cannot share the Pareto front while proof logging"
+        );
+        self.shared_front = Some(front.connect());
+        Ok(())
+    }
+
+    fn attach_logger<L: WriteSolverLog + 'static>(
+        &mut self,
+        logger: L,
+        filter: LogFilter,
+    ) -> LoggerHandle {
+        self.logger
+            .get_or_insert_with(MultiLogger::default)
+            .attach(logger, filter)
+    }
+
+    fn detach_logger(&mut self, handle: LoggerHandle) -> Option<Box<dyn WriteSolverLog>> {
+        let multi = self.logger.as_mut()?;
+        let logger = multi.detach(handle);
+        if multi.is_empty() {
+            self.logger = None;
+        }
+        logger
+    }
+
+    fn set_solution_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&NonDomPoint, &Assignment) -> SolutionAction + 'static,
+    {
+        self.sol_callback = Some(Box::new(callback));
+    }
+
+    fn clear_solution_callback(&mut self) {
+        self.sol_callback = None;
     }
 
     /// Converts an internal cost vector to an external one. Internal cost is
@@ -364,6 +612,28 @@ where
             .collect()
     }
 
+    /// Converts an external cost vector to the smallest internal cost vector
+    /// whose external cost is at least the given one
+    fn internalize_external_costs(&self, costs: &[isize]) -> Vec<usize> {
+        debug_assert_eq!(costs.len(), self.stats.n_objs);
+        costs
+            .iter()
+            .enumerate()
+            .map(|(idx, &cst)| match self.objs[idx] {
+                Objective::Weighted { offset, .. } => (cst - offset).try_into().unwrap_or(0),
+                Objective::Unweighted {
+                    offset,
+                    unit_weight,
+                    ..
+                } => {
+                    let cst: usize = (cst - offset).try_into().unwrap_or(0);
+                    cst.div_ceil(unit_weight)
+                }
+                Objective::Constant { .. } => 0,
+            })
+            .collect()
+    }
+
     /// Blocks the current Pareto-MCS by blocking all blocking variables that are set
     fn block_pareto_mcs(&self, sol: Assignment) -> Clause {
         let mut blocking_clause = Clause::new();
@@ -379,10 +649,12 @@ where
             .expect("Tautological blocking clause")
     }
 
-    /// Checks the termination flag and terminates if appropriate
+    /// Checks the termination flag and time limits and terminates if appropriate
     fn check_termination(&self) -> MaybeTerminated {
         if self.term_flag.load(Ordering::Relaxed) {
             MaybeTerminated::Terminated(Termination::Interrupted)
+        } else if self.deadlines.passed() {
+            MaybeTerminated::Terminated(Termination::Timeout)
         } else {
             MaybeTerminated::Done(())
         }
@@ -392,6 +664,8 @@ where
     fn log_candidate(&mut self, costs: &[usize], phase: Phase) -> MaybeTerminatedError {
         debug_assert_eq!(costs.len(), self.stats.n_objs);
         self.stats.n_candidates += 1;
+        self.approx
+            .add_feasible(self.externalize_internal_costs(costs));
         // Dispatch to logger
         if let Some(logger) = &mut self.logger {
             logger
@@ -408,21 +682,41 @@ where
         Done(())
     }
 
-    /// Logs an oracle call. Can return a termination if the oracle call limit is reached.
-    fn log_oracle_call(&mut self, result: SolverResult) -> MaybeTerminatedError {
+    /// Logs an oracle call and updates the oracle call limit
+    fn log_oracle_call(&mut self, result: SolverResult) -> anyhow::Result<()> {
         self.stats.n_oracle_calls += 1;
         // Dispatch to logger
         if let Some(logger) = &mut self.logger {
             logger.log_oracle_call(result).context("logger failed")?;
         }
-        // Update limit and check termination
         if let Some(oracle_calls) = &mut self.lims.oracle_calls {
-            *oracle_calls -= 1;
-            if *oracle_calls == 0 {
-                return Terminated(Termination::OracleCallsLimit);
-            }
+            *oracle_calls = oracle_calls.saturating_sub(1);
         }
-        Done(())
+        Ok(())
+    }
+
+    /// Checks whether the oracle call limit allows for another oracle call.
+    /// The limit is checked before rather than after calling the oracle so
+    /// that the result of the last allowed call is not lost.
+    fn check_oracle_call_limit(&self) -> MaybeTerminated {
+        if self.lims.oracle_calls == Some(0) {
+            MaybeTerminated::Terminated(Termination::OracleCallsLimit)
+        } else {
+            MaybeTerminated::Done(())
+        }
+    }
+
+    /// Keeps a candidate to resume from if the result is a termination
+    fn keep_candidate<T>(
+        &mut self,
+        res: MaybeTerminatedError<T>,
+        costs: &[usize],
+        solution: &Assignment,
+    ) -> MaybeTerminatedError<T> {
+        if matches!(res, Terminated(_)) {
+            self.interrupted_candidate = Some((costs.to_vec(), solution.clone()));
+        }
+        res
     }
 
     /// Logs a solution. Can return a termination if the solution limit is reached.
@@ -442,9 +736,28 @@ where
         Done(())
     }
 
+    /// Passes a Pareto-optimal solution to the solution callback. Returns a
+    /// termination if the callback asks to stop.
+    fn yield_to_callback(
+        &mut self,
+        non_dominated: &NonDomPoint,
+        solution: &Assignment,
+    ) -> MaybeTerminatedError {
+        if let Some(callback) = &mut self.sol_callback {
+            if callback(non_dominated, solution) == SolutionAction::Stop {
+                return Terminated(Termination::Callback);
+            }
+        }
+        Done(())
+    }
+
     /// Logs a non-dominated point. Can return a termination if the non-dominated point limit is reached.
     fn log_non_dominated(&mut self, non_dominated: &NonDomPoint) -> MaybeTerminatedError {
         self.stats.n_non_dominated += 1;
+        self.approx.add_proven(non_dominated.costs().clone());
+        if let Some(shared_front) = &self.shared_front {
+            shared_front.publish(non_dominated.costs().clone());
+        }
         // Dispatch to logger
         if let Some(logger) = &mut self.logger {
             logger
@@ -497,6 +810,129 @@ where
     }
 }
 
+#[cfg(feature = "interrupt-oracle")]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    /// Starts a watchdog that interrupts the oracle once a time limit of the
+    /// current solving run has passed
+    fn attach_deadlines(&mut self) {
+        self.watchdog = None;
+        if self.deadlines.is_none() {
+            return;
+        }
+        self.watchdog = Some(Watchdog::spawn(
+            self.deadlines,
+            self.oracle_interrupter.clone(),
+        ));
+    }
+
+    /// Stops the watchdog started by [`Kernel::attach_deadlines`]
+    fn detach_deadlines(&mut self) {
+        self.watchdog = None;
+    }
+}
+
+#[cfg(not(feature = "interrupt-oracle"))]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    /// Without oracle interrupts, the time limits are only checked between
+    /// oracle calls
+    fn attach_deadlines(&mut self) {}
+
+    /// Stops the watchdog started by [`Kernel::attach_deadlines`]
+    fn detach_deadlines(&mut self) {}
+}
+
+/// A thread interrupting the oracle once a deadline has passed. The thread is
+/// stopped when the watchdog is dropped.
+#[cfg(feature = "interrupt-oracle")]
+struct Watchdog {
+    /// Flag to stop the thread
+    stop: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "interrupt-oracle")]
+impl Watchdog {
+    /// How often the watchdog checks the deadlines
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    fn spawn(
+        deadlines: Deadlines,
+        interrupter: Arc<Mutex<Box<dyn rustsat::solvers::InterruptSolver + Send>>>,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                // keep interrupting, since the oracle might only be called
+                // after the deadline or be replaced in the meantime
+                if deadlines.passed() {
+                    interrupter.lock().unwrap().interrupt();
+                }
+                std::thread::sleep(Self::POLL_INTERVAL);
+            }
+        });
+        Watchdog { stop }
+    }
+}
+
+#[cfg(feature = "interrupt-oracle")]
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Deadlines derived from the time limits of a solving run
+#[derive(Clone, Copy, Default)]
+struct Deadlines {
+    /// The wall-clock time at which to terminate
+    wall: Option<Instant>,
+    /// The CPU time at the start of solving and the CPU time budget. The CPU
+    /// time is measured for the whole process, since the watchdog checks the
+    /// deadlines from its own thread.
+    cpu: Option<(ProcessTime, Duration)>,
+}
+
+impl Deadlines {
+    fn new(limits: &Limits) -> Self {
+        Self::since(limits, Instant::now(), ProcessTime::now())
+    }
+
+    /// Gets the deadlines of time limits that started at the given wall-clock
+    /// and CPU time
+    fn since(limits: &Limits, wall: Instant, cpu: ProcessTime) -> Self {
+        Deadlines {
+            wall: limits.time.map(|time| wall + time),
+            cpu: limits.cpu_time.map(|time| (cpu, time)),
+        }
+    }
+
+    /// Checks whether no time limit is set
+    fn is_none(&self) -> bool {
+        self.wall.is_none() && self.cpu.is_none()
+    }
+
+    /// Checks whether one of the deadlines has passed
+    fn passed(&self) -> bool {
+        if let Some(wall) = self.wall {
+            if Instant::now() >= wall {
+                return true;
+            }
+        }
+        if let Some((start, budget)) = self.cpu {
+            if start.elapsed() >= budget {
+                return true;
+            }
+        }
+        false
+    }
+}
+
 #[cfg(feature = "interrupt-oracle")]
 impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
@@ -653,35 +1089,117 @@ impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG> {
     }
 }
 
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
+where
+    ProofW: io::Write + 'static,
+{
+    /// Records the result of a solving run. If the run was not terminated
+    /// early, the Pareto front is complete. When proof logging, the result is
+    /// also recorded in the proof.
+    fn end_solving(&mut self, res: MaybeTerminatedError) -> MaybeTerminatedError {
+        if matches!(res, Done(_)) {
+            self.approx.set_complete();
+        }
+        self.end_partial_solving(res)
+    }
+
+    /// Records the result of a solving run of an algorithm that does not
+    /// search for the entire Pareto front, i.e., the Pareto front is never
+    /// complete. When proof logging, the result is also recorded in the proof.
+    fn end_partial_solving(&mut self, res: MaybeTerminatedError) -> MaybeTerminatedError {
+        self.detach_deadlines();
+        if let Some(proof_stuff) = &self.proof_stuff {
+            let approx = self.approximation();
+            proofs::conclude_run(&approx, proof_stuff, &mut self.oracle)?;
+        }
+        res
+    }
+}
+
 #[oracle_bounds]
 impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
     O: SolveIncremental,
-    ProofW: io::Write,
+    ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
     /// Yields Pareto-optimal solutions. The given assumptions must only allow
     /// for solutions at the non-dominated point with given cost. If the options
     /// ask for enumeration, will enumerate all solutions at this point.
+    ///
+    /// When proof logging, the enumerated solutions are only blocked under
+    /// temporary assumptions and every solution but the last one is logged in
+    /// the proof. The last solution is returned and must be used as the
+    /// witness for the P-minimal cut at this point, which then also excludes
+    /// all enumerated solutions permanently.
+    ///
+    /// If solving is terminated while yielding, the non-dominated point is
+    /// still closed with the solutions enumerated so far and the termination
+    /// is returned alongside the witness. The caller must exclude the point
+    /// before raising the termination, so that solving can be resumed.
     fn yield_solutions<Col: Extend<NonDomPoint>>(
         &mut self,
         costs: Vec<usize>,
         assumps: &[Lit],
         mut solution: Assignment,
         collector: &mut Col,
-    ) -> MaybeTerminatedError {
+    ) -> MaybeTerminatedError<(Assignment, MaybeTerminated)> {
         debug_assert_eq!(costs.len(), self.stats.n_objs);
         self.log_routine_start("yield solutions")?;
         self.unphase_solution()?;
 
         // Create Pareto point
         let mut non_dominated = NonDomPoint::new(self.externalize_internal_costs(&costs));
+        // Literals under which enumerated solutions are blocked when proof logging
+        let mut tmp_lits = vec![];
+
+        let enum_term = match self.enumerate_solutions(
+            &costs,
+            assumps,
+            &mut solution,
+            &mut non_dominated,
+            &mut tmp_lits,
+        ) {
+            Done(_) => MaybeTerminated::Done(()),
+            Terminated(term) => MaybeTerminated::Terminated(term),
+            Error(err) => {
+                let nd_term = self.log_non_dominated(&non_dominated);
+                collector.extend([non_dominated]);
+                nd_term?;
+                return Error(err);
+            }
+        };
 
+        self.deactivate_tmp_lits(&tmp_lits, &mut solution)?;
+        let pp_term = self.log_non_dominated(&non_dominated);
+        collector.extend([non_dominated]);
+        self.log_routine_end()?;
+        let term = match (enum_term, pp_term) {
+            (MaybeTerminated::Terminated(term), _) | (_, Terminated(term)) => {
+                MaybeTerminated::Terminated(term)
+            }
+            (_, Error(err)) => return Error(err),
+            _ => MaybeTerminated::Done(()),
+        };
+        Done((solution, term))
+    }
+
+    /// Enumerates the solutions at a non-dominated point for
+    /// [`Kernel::yield_solutions`]. The point is not closed here.
+    fn enumerate_solutions(
+        &mut self,
+        costs: &[usize],
+        assumps: &[Lit],
+        solution: &mut Assignment,
+        non_dominated: &mut NonDomPoint,
+        tmp_lits: &mut Vec<Lit>,
+    ) -> MaybeTerminatedError {
         loop {
             debug_assert_eq!(
                 (0..self.stats.n_objs)
                     .map(|idx| {
-                        self.get_cost_with_heuristic_improvements(idx, &mut solution, false)
+                        self.get_cost_with_heuristic_improvements(idx, solution, false)
                             .unwrap()
                     })
                     .collect::<Vec<_>>(),
@@ -689,80 +1207,111 @@ where
             );
 
             // Truncate internal solution to only include instance variables
-            solution = solution.truncate(self.var_manager.max_orig_var());
+            let ext_sol = solution.clone().truncate(self.var_manager.max_orig_var());
 
-            non_dominated.add_sol(solution.clone());
-            match self.log_solution() {
-                Done(_) => (),
-                Terminated(term) => {
-                    let nd_term = self.log_non_dominated(&non_dominated);
-                    collector.extend([non_dominated]);
-                    nd_term?;
-                    return Terminated(term);
-                }
-                Error(err) => {
-                    let nd_term = self.log_non_dominated(&non_dominated);
-                    collector.extend([non_dominated]);
-                    nd_term?;
-                    return Error(err);
-                }
-            }
+            non_dominated.add_sol(ext_sol.clone());
+            self.log_solution()?;
+            self.yield_to_callback(non_dominated, &ext_sol)?;
             if match self.opts.enumeration {
                 EnumOptions::NoEnum => true,
                 EnumOptions::Solutions(Some(limit)) => non_dominated.n_sols() >= limit,
                 EnumOptions::PMCSs(Some(limit)) => non_dominated.n_sols() >= limit,
                 _unlimited => false,
             } {
-                let pp_term = self.log_non_dominated(&non_dominated);
-                collector.extend([non_dominated]);
-                self.log_routine_end()?;
-                return pp_term;
+                return Done(());
             }
             self.check_termination()?;
 
             // Block last solution
-            match self.opts.enumeration {
-                EnumOptions::Solutions(_) => {
-                    self.oracle.add_clause((self.block_clause_gen)(solution))?
-                }
-                EnumOptions::PMCSs(_) => self.oracle.add_clause(self.block_pareto_mcs(solution))?,
+            let block_clause = match self.opts.enumeration {
+                EnumOptions::Solutions(_) => (self.block_clause_gen)(ext_sol),
+                EnumOptions::PMCSs(_) => self.block_pareto_mcs(ext_sol),
                 EnumOptions::NoEnum => panic!("Should never reach this"),
-            }
+            };
 
             // Find next solution
-            let res = self.solve_assumps(assumps)?;
+            let res = if let Some(proof_stuff) = &self.proof_stuff {
+                // The solution might still be needed as the witness for the
+                // P-minimal cut, so it can only be blocked temporarily
+                let tmp_lit = self.var_manager.new_var().pos_lit();
+                proofs::add_tmp_clause(block_clause, tmp_lit, proof_stuff, &mut self.oracle)?;
+                tmp_lits.push(tmp_lit);
+                let mut assumps = Vec::from(assumps);
+                assumps.extend(tmp_lits.iter());
+                self.solve_assumps(&assumps)?
+            } else {
+                self.oracle.add_clause(block_clause)?;
+                self.solve_assumps(assumps)?
+            };
             if res == SolverResult::Unsat {
-                let pp_term = self.log_non_dominated(&non_dominated);
                 // All solutions enumerated
-                collector.extend([non_dominated]);
-                self.log_routine_end()?;
-                return pp_term;
+                return Done(());
             }
-            self.check_termination()?;
-            solution = self.oracle.solution(self.var_manager.max_var().unwrap())?;
+            if let Some(proof_stuff) = &self.proof_stuff {
+                // The previous solution is not the witness anymore
+                proofs::log_enumerated_solution(
+                    solution,
+                    self.var_manager.max_enc_var(),
+                    proof_stuff,
+                    &mut self.oracle,
+                )?;
+            }
+            *solution = self.oracle.solution(self.var_manager.max_var().unwrap())?;
         }
     }
+
+    /// Deactivates the temporary blocking clauses of enumerated solutions when
+    /// proof logging and sets the temporary literals to false in the witness
+    /// solution
+    fn deactivate_tmp_lits(
+        &mut self,
+        tmp_lits: &[Lit],
+        witness: &mut Assignment,
+    ) -> anyhow::Result<()> {
+        let Some(proof_stuff) = &self.proof_stuff else {
+            return Ok(());
+        };
+        proofs::deactivate_tmp_lits(tmp_lits, proof_stuff, &mut self.oracle)?;
+        for &lit in tmp_lits {
+            witness.assign_lit(!lit);
+        }
+        Ok(())
+    }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
     /// Performs linear sat-unsat search on a given objective and yields
-    /// solutions found at the optimum.
-    fn linsu_yield<Col>(
+    /// solutions found at the optimum. The returned solution is the witness
+    /// for the P-minimal cut when proof logging. As for
+    /// [`Kernel::yield_solutions`], a termination while yielding is returned
+    /// last and must be raised once the point is excluded.
+    #[allow(clippy::type_complexity)]
+    fn linsu_yield<PBE, CE, Col>(
         &mut self,
         obj_idx: usize,
-        encoding: &mut ObjEncoding<DbGte, DbTotalizer>,
+        encoding: &mut ObjEncoding<PBE, CE>,
         base_assumps: &[Lit],
         upper_bound: Option<(usize, Option<Assignment>)>,
         lower_bound: Option<usize>,
         collector: &mut Col,
-    ) -> MaybeTerminatedError<Option<(usize, Assignment, Option<pidgeons::AbsConstraintId>)>>
+    ) -> MaybeTerminatedError<
+        Option<(
+            usize,
+            Assignment,
+            Option<pidgeons::AbsConstraintId>,
+            MaybeTerminated,
+        )>,
+    >
     where
         Col: Extend<NonDomPoint>,
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
     {
         let Some((cost, mut sol, lb_id)) =
             self.linsu(obj_idx, encoding, base_assumps, upper_bound, lower_bound)?
@@ -781,8 +1330,8 @@ where
         let mut assumps = Vec::from(base_assumps);
         self.extend_encoding(encoding, cost..cost + 1)?;
         assumps.extend(encoding.enforce_ub(cost).unwrap());
-        self.yield_solutions(costs, &assumps, sol.clone(), collector)?;
-        Done(Some((cost, sol, lb_id)))
+        let (sol, yield_term) = self.yield_solutions(costs, &assumps, sol, collector)?;
+        Done(Some((cost, sol, lb_id, yield_term)))
     }
 }
 
@@ -808,20 +1357,126 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
 {
-    /// Performs linear sat-unsat search on a given objective.
-    fn linsu(
+    /// Checks known solutions against the instance and queues them to seed
+    /// the search with. Returns the external costs of the solutions.
+    fn add_warm_start<I>(&mut self, solutions: I) -> anyhow::Result<Vec<Vec<isize>>>
+    where
+        I: IntoIterator<Item = Assignment>,
+    {
+        let max_orig_var = self.var_manager.max_orig_var();
+        // the relaxation literals of non-unit soft clauses are not part of
+        // the given solutions
+        let mut relax_lits: Vec<_> = self
+            .objs
+            .iter()
+            .flat_map(|obj| obj.iter().map(|(lit, _)| lit))
+            .filter(|lit| lit.var() > max_orig_var)
+            .collect();
+        relax_lits.sort_unstable();
+        relax_lits.dedup();
+        let mut all_costs = vec![];
+        for (idx, sol) in solutions.into_iter().enumerate() {
+            if let Some(var) = (0..max_orig_var.idx32() + 1)
+                .map(Var::new)
+                .find(|&var| sol.var_value(var) == TernaryVal::DontCare)
+            {
+                anyhow::bail!("warm-start solution {idx} does not assign variable {var}");
+            }
+            let mut assumps: Vec<_> = sol
+                .into_iter()
+                .filter(|lit| lit.var() <= max_orig_var)
+                .collect();
+            let n_orig = assumps.len();
+            assumps.extend(relax_lits.iter().map(|&lit| !lit));
+            let mut res = self.oracle.solve_assumps(&assumps)?;
+            self.log_oracle_call(res)?;
+            if res == SolverResult::Unsat {
+                // set the relaxation literals of the soft clauses that the
+                // solution violates
+                for rel_idx in n_orig..assumps.len() {
+                    let mut single = assumps[..n_orig].to_vec();
+                    single.push(assumps[rel_idx]);
+                    let single_res = self.oracle.solve_assumps(&single)?;
+                    self.log_oracle_call(single_res)?;
+                    if single_res == SolverResult::Unsat {
+                        assumps[rel_idx] = !assumps[rel_idx];
+                    }
+                }
+                res = self.oracle.solve_assumps(&assumps)?;
+                self.log_oracle_call(res)?;
+            }
+            anyhow::ensure!(
+                res == SolverResult::Sat,
+                "warm-start solution {idx} is infeasible"
+            );
+            // the costs are those of the given solution, not of the completion by the oracle
+            let sol: Assignment = assumps.iter().copied().collect();
+            let costs: Vec<_> = self
+                .objs
+                .iter()
+                .map(|obj| {
+                    obj.iter()
+                        .filter(|&(lit, _)| sol.lit_value(lit) == TernaryVal::True)
+                        .fold(0, |cost, (_, w)| cost + w)
+                })
+                .collect();
+            all_costs.push(self.externalize_internal_costs(&costs));
+            self.warm_start.push((costs, assumps));
+        }
+        Ok(all_costs)
+    }
+
+    /// Takes the next warm-start solution that is not yet dominated by a found
+    /// non-dominated point and completes it with the oracle. Returns [`None`]
+    /// once all warm-start solutions are used up.
+    fn warm_start_candidate(&mut self) -> MaybeTerminatedError<Option<(Vec<usize>, Assignment)>> {
+        while let Some((costs, assumps)) = self.warm_start.pop() {
+            let res = match self.solve_assumps(&assumps) {
+                Terminated(term) => {
+                    // keep the solution for the next solving run
+                    self.warm_start.push((costs, assumps));
+                    return Terminated(term);
+                }
+                res => res?,
+            };
+            if res == SolverResult::Unsat {
+                // dominated by a found non-dominated point
+                continue;
+            }
+            let (costs, solution) = self.get_solution_and_internal_costs(
+                self.opts
+                    .heuristic_improvements
+                    .solution_tightening
+                    .wanted(Phase::OuterLoop),
+            )?;
+            let cand_term = self.log_candidate(&costs, Phase::OuterLoop);
+            self.keep_candidate(cand_term, &costs, &solution)?;
+            return Done(Some((costs, solution)));
+        }
+        Done(None)
+    }
+
+    /// Performs linear sat-unsat search on a given objective. If terminated
+    /// after a solution was found, the best solution so far is kept as the
+    /// interrupted candidate.
+    fn linsu<PBE, CE>(
         &mut self,
         obj_idx: usize,
-        encoding: &mut ObjEncoding<DbGte, DbTotalizer>,
+        encoding: &mut ObjEncoding<PBE, CE>,
         base_assumps: &[Lit],
         upper_bound: Option<(usize, Option<Assignment>)>,
         lower_bound: Option<usize>,
-    ) -> MaybeTerminatedError<Option<(usize, Assignment, Option<pidgeons::AbsConstraintId>)>> {
+    ) -> MaybeTerminatedError<Option<(usize, Assignment, Option<pidgeons::AbsConstraintId>)>>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         use rustsat::solvers::Solve;
 
         self.log_routine_start("linsu")?;
@@ -856,7 +1511,17 @@ where
             assumps.drain(base_assumps.len()..);
             self.extend_encoding(encoding, bound..bound + 1)?;
             assumps.extend(encoding.enforce_ub(bound).unwrap());
-            match self.solve_assumps(&assumps)? {
+            let res = self.solve_assumps(&assumps);
+            if let (Terminated(_), Some(sol)) = (&res, &mut sol) {
+                let costs: Vec<_> = (0..self.stats.n_objs)
+                    .map(|oidx| {
+                        self.get_cost_with_heuristic_improvements(oidx, sol, false)
+                            .unwrap()
+                    })
+                    .collect();
+                self.interrupted_candidate = Some((costs, sol.clone()));
+            }
+            match res? {
                 SolverResult::Sat => {
                     let mut thissol = self.oracle.solution(self.var_manager.max_var().unwrap())?;
                     let new_cost =
@@ -868,7 +1533,9 @@ where
                                 .unwrap()
                         })
                         .collect();
-                    self.log_candidate(&costs, Phase::Linsu)?;
+                    let cand_term = self.log_candidate(&costs, Phase::Linsu);
+                    let cand_term = self.keep_candidate(cand_term, &costs, &thissol);
+                    cand_term?;
                     sol = Some(thissol);
                     cost = new_cost;
                     if cost <= lower_bound {
@@ -914,13 +1581,17 @@ where
         Done(Some((cost, sol.unwrap(), lb_id)))
     }
 
-    fn extend_encoding(
+    fn extend_encoding<PBE, CE>(
         &mut self,
-        encoding: &mut ObjEncoding<DbGte, DbTotalizer>,
+        encoding: &mut ObjEncoding<PBE, CE>,
         range: Range<usize>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<()>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-            let proof: *mut _ = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof: *mut _ = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
             #[cfg(feature = "verbose-proofs")]
             {
                 unsafe { &mut *proof }.comment(&format_args!(
@@ -928,7 +1599,7 @@ where
                     range.start, range.end,
                 ))?;
             }
-            let mut collector = CadicalCertCollector::new(&mut self.oracle, pt_handle);
+            let mut collector = CertCollector::new(&mut self.oracle, pt_handle);
             encoding.encode_ub_change_cert(
                 range,
                 &mut collector,
@@ -950,22 +1621,24 @@ where
     /// Wrapper around the oracle with call logging and interrupt detection.
     /// Assumes that the oracle is unlimited.
     fn solve(&mut self) -> MaybeTerminatedError<SolverResult> {
+        self.check_oracle_call_limit()?;
         self.log_routine_start("oracle call")?;
         let res = self.oracle.solve()?;
         self.log_routine_end()?;
-        self.check_termination()?;
         self.log_oracle_call(res)?;
+        self.check_termination()?;
         Done(res)
     }
 
     /// Wrapper around the oracle with call logging and interrupt detection.
     /// Assumes that the oracle is unlimited.
     fn solve_assumps(&mut self, assumps: &[Lit]) -> MaybeTerminatedError<SolverResult> {
+        self.check_oracle_call_limit()?;
         self.log_routine_start("oracle call")?;
         let res = self.oracle.solve_assumps(assumps)?;
         self.log_routine_end()?;
-        self.check_termination()?;
         self.log_oracle_call(res)?;
+        self.check_termination()?;
         Done(res)
     }
 }
@@ -1015,3 +1688,20 @@ where
 pub fn default_blocking_clause(sol: Assignment) -> Clause {
     Clause::from_iter(sol.into_iter().map(Lit::not))
 }
+
+/// Adds a hard constraint to a clause collector. Cardinality and
+/// pseudo-Boolean constraints are encoded with the default RustSAT encodings.
+fn add_constraint<Col: CollectClauses>(
+    constr: Constraint,
+    collector: &mut Col,
+    var_manager: &mut VarManager,
+) -> anyhow::Result<()> {
+    match constr {
+        Constraint::Clause(cl) => collector.add_clause(cl)?,
+        Constraint::Card(constr) => {
+            card::default_encode_cardinality_constraint(constr, collector, var_manager)?
+        }
+        Constraint::Pb(constr) => pb::default_encode_pb_constraint(constr, collector, var_manager)?,
+    }
+    Ok(())
+}
@@ -7,7 +7,6 @@
 
 use std::{fs, io};
 
-use cadical_veripb_tracer::CadicalCertCollector;
 use pidgeons::{ConstraintId, OperationLike, OperationSequence, VarLike};
 use rustsat::{
     clause,
@@ -27,14 +26,19 @@ use rustsat::{
 use scuttle_proc::{oracle_bounds, KernelFunctions};
 
 use crate::{
+    checkpoint::{AlgState, Progress},
+    encodings::{ensure_proof_support, CertEncoding},
     options::{AfterCbOptions, CoreBoostingOptions},
     termination::ensure,
-    types::{NonDomPoint, ParetoFront, VarManager},
-    EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits,
+    types::{Constraint, NonDomPoint, ParetoFront, VarManager},
+    EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits, MaybeTerminated,
     MaybeTerminatedError::{self, Done},
 };
 
-use super::{coreboosting::MergeOllRef, proofs, CoreBoost, Kernel, ObjEncoding, Objective};
+use super::{
+    coreboosting::MergeOllRef, proofs, CertCollector, Checkpoint, CoreBoost, Kernel, ObjEncoding,
+    Objective, WarmStart,
+};
 
 /// The BiOptSat algorithm type
 ///
@@ -65,15 +69,20 @@ pub struct BiOptSat<
     pareto_front: ParetoFront,
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG> super::Solve
-    for BiOptSat<rustsat_cadical::CaDiCaL<'term, 'learn>, DbGte, DbTotalizer, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Solve for BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    CE: card::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    O: SolveIncremental + SolveStats,
     BCG: Fn(Assignment) -> Clause,
     ProofW: io::Write + 'static,
 {
     fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
         self.kernel.start_solving(limits);
-        self.alg_main()
+        self.kernel.attach_deadlines();
+        let res = self.alg_main();
+        self.kernel.end_solving(res)
     }
 
     fn all_stats(
@@ -115,7 +124,8 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -128,8 +138,8 @@ impl<'term, 'learn, PBE, CE, ProofW, OInit, BCG> super::InitCert
     for BiOptSat<rustsat_cadical::CaDiCaL<'term, 'learn>, PBE, CE, ProofW, OInit, BCG>
 where
     ProofW: io::Write,
-    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
-    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)> + CertEncoding,
+    CE: card::BoundUpperIncremental + FromIterator<Lit> + CertEncoding,
     OInit: Initialize<rustsat_cadical::CaDiCaL<'term, 'learn>>,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
@@ -147,10 +157,12 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
+        ensure_proof_support::<PBE, CE>()?;
         let kernel = Kernel::new_cert(clauses, objs, var_manager, block_clause_gen, proof, opts)?;
         Ok(Self::init(kernel))
     }
@@ -242,38 +254,138 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    BiOptSat<rustsat_cadical::CaDiCaL<'learn, 'term>, DbGte, DbTotalizer, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
     /// The solving algorithm main routine.
     fn alg_main(&mut self) -> MaybeTerminatedError {
-        self.kernel.bioptsat(
+        // Resume from the candidate a previous run was terminated at, or start
+        // from the known solution with the lowest costs. The search only has a
+        // single starting point, so the other known solutions are not used.
+        let starting_point = match self.kernel.interrupted_candidate.take() {
+            Some(cand) => Some(cand),
+            None => {
+                self.kernel
+                    .warm_start
+                    .sort_unstable_by(|(costs_a, _), (costs_b, _)| costs_b.cmp(costs_a));
+                let cand = self.kernel.warm_start_candidate()?;
+                self.kernel.warm_start.clear();
+                cand
+            }
+        }
+        .map(|(costs, sol)| (costs[0], sol));
+        let yield_term = self.kernel.bioptsat(
             (0, 1),
             &mut self.obj_encs,
             &[],
-            None,
+            starting_point,
             (None, None),
             |_| None,
             &mut self.pareto_front,
-        )
+        )?;
+        yield_term?;
+        Done(())
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> Checkpoint for BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
+where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    fn progress(&self) -> Progress {
+        let dec_bound = self
+            .pareto_front
+            .iter()
+            .map(|pp| self.kernel.internalize_external_costs(pp.costs())[1])
+            .min();
+        self.kernel
+            .progress(&self.pareto_front, AlgState::BiOptSat { dec_bound })
+    }
+
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()> {
+        let AlgState::BiOptSat { dec_bound } = progress.state else {
+            anyhow::bail!("the progress was not taken of the bioptsat algorithm");
+        };
+        self.kernel.restore_front(
+            progress.pareto_front,
+            &progress.lower_bounds,
+            &mut self.obj_encs,
+            &mut self.pareto_front,
+        )?;
+        // Restore the cut on the decreasing objective. If the bound cannot be
+        // decreased further, blocking the dominated solutions suffices.
+        if let Some(bound) = dec_bound.filter(|&b| b > self.obj_encs[1].offset()) {
+            self.kernel
+                .extend_encoding(&mut self.obj_encs[1], bound - 1..bound)?;
+            for lit in self.obj_encs[1].enforce_ub(bound - 1)? {
+                self.kernel.oracle.add_unit(lit)?;
+            }
+        }
+        Ok(())
     }
 }
 
-impl<'learn, 'term, PBE, CE, ProofW, OInit, BCG> CoreBoost
-    for BiOptSat<rustsat_cadical::CaDiCaL<'learn, 'term>, PBE, CE, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> WarmStart for BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    /// BiOptSat only starts its search from the known solution with the lowest
+    /// costs that is not dominated yet, the other solutions are discarded.
+    fn warm_start<I>(&mut self, solutions: I) -> anyhow::Result<Vec<Vec<isize>>>
+    where
+        I: IntoIterator<Item = Assignment>,
+    {
+        self.kernel.add_warm_start(solutions)
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> CoreBoost for BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
-    OInit: Initialize<rustsat_cadical::CaDiCaL<'learn, 'term>>,
+    OInit: Initialize<O>,
 {
-    fn core_boost(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool> {
         ensure!(
             self.kernel.stats.n_solve_calls == 0,
             "cannot perform core boosting after solve has been called"
         );
+        self.kernel.start_core_boosting(&limits);
+        let res = self.core_boost_main(opts);
+        self.kernel.detach_deadlines();
+        res
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> BiOptSat<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    /// Performs core boosting and merges the OLL reformulations into the
+    /// objective encodings
+    fn core_boost_main(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
         let Some(cb_res) = self.kernel.core_boost()? else {
             return Done(false);
         };
@@ -303,7 +415,7 @@ where
                 if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
                     if !reform.reformulations.is_empty() {
                         // delete remaining reformulation constraints from proof
-                        let proof = self.kernel.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                        let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
                         #[cfg(feature = "verbose-proofs")]
                         proof.comment(&format_args!(
                             "deleting remaining reformulation constraints from OLL of objective {oidx}"
@@ -331,9 +443,10 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
@@ -346,17 +459,24 @@ where
     /// `lookup`: for a value of the increasing objective, checks if the
     /// non-dominated point has already been discovered and returns the
     /// corresponding value of the decreasing objective
-    pub fn bioptsat<Lookup, Col>(
+    ///
+    /// If solving is terminated while yielding the solutions of a
+    /// non-dominated point, the termination is only raised once the point is
+    /// cut off. For the last point, the termination is instead returned, since
+    /// the search is complete.
+    pub fn bioptsat<PBE, CE, Lookup, Col>(
         &mut self,
         (inc_obj, dec_obj): (usize, usize),
-        encodings: &mut [ObjEncoding<DbGte, DbTotalizer>],
+        encodings: &mut [ObjEncoding<PBE, CE>],
         base_assumps: &[Lit],
         starting_point: Option<(usize, Assignment)>,
         (inc_lb, dec_lb): (Option<usize>, Option<usize>),
         lookup: Lookup,
         collector: &mut Col,
-    ) -> MaybeTerminatedError
+    ) -> MaybeTerminatedError<MaybeTerminated>
     where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
         Lookup: Fn(usize) -> Option<usize>,
         Col: Extend<NonDomPoint>,
     {
@@ -373,7 +493,7 @@ where
         } else {
             let res = self.solve_assumps(&assumps)?;
             if res == SolverResult::Unsat {
-                return Done(());
+                return Done(MaybeTerminated::Done(()));
             }
             let mut sol = self.oracle.solution(self.var_manager.max_var().unwrap())?;
             let cost = self.get_cost_with_heuristic_improvements(inc_obj, &mut sol, true)?;
@@ -394,12 +514,13 @@ where
             else {
                 // no solutions
                 self.log_routine_end()?;
-                return Done(());
+                return Done(MaybeTerminated::Done(()));
             };
             (inc_cost, sol) = (new_inc_cost, new_sol);
 
             dec_cost = self.get_cost_with_heuristic_improvements(dec_obj, &mut sol, false)?;
             let mut dec_lb_id = None;
+            let mut yield_term = MaybeTerminated::Done(());
             if let Some(found) = lookup(inc_cost) {
                 // lookup not supported with proofs
                 debug_assert!(self.proof_stuff.is_none());
@@ -410,7 +531,7 @@ where
                 assumps.drain(base_assumps.len()..);
                 assumps.extend(encodings[0].enforce_ub(inc_cost).unwrap());
                 // minimize dec_obj
-                (dec_cost, sol, dec_lb_id) = self
+                (dec_cost, sol, dec_lb_id, yield_term) = self
                     .linsu_yield(
                         dec_obj,
                         &mut encodings[1],
@@ -436,9 +557,9 @@ where
                         proof_stuff,
                         &mut self.oracle,
                     )?;
-                    let proof = self
-                        .oracle
-                        .proof_tracer_mut(&proof_stuff.pt_handle)
+                    let proof = proof_stuff
+                        .pt_handle
+                        .tracer_mut(&mut self.oracle)
                         .proof_mut();
                     // derive cut that will be added
                     let _cut_id = if inc_cost <= encodings[0].offset() {
@@ -454,7 +575,7 @@ where
                             #[cfg(feature = "verbose-proofs")]
                             {
                                 if encodings[0].is_buffer_empty() {
-                                    let (olit, _) = encodings[0].output_proof_details(inc_cost);
+                                    let (olit, _) = encodings[0].output_proof_details(inc_cost)?;
                                     proof.equals(
                                         &pidgeons::Axiom::from(olit),
                                         Some(pidgeons::ConstraintId::from(lb_id)),
@@ -478,7 +599,8 @@ where
                         )?;
                     }
                 }
-                break;
+                self.log_routine_end()?;
+                return Done(yield_term);
             }
             // skip to next non-dom
             self.extend_encoding(&mut encodings[1], dec_cost - 1..dec_cost)?;
@@ -499,23 +621,23 @@ where
 
                 // while we know the assumptions, simplify dec_lb_id
                 let (first_olit, first_sems) =
-                    encodings[0].output_proof_details(encodings[0].next_higher(inc_cost));
+                    encodings[0].output_proof_details(encodings[0].next_higher(inc_cost))?;
                 dec_lb_id = if encodings[0].is_buffer_empty() {
                     if assumps.len() <= 1 {
                         debug_assert!(assumps.len() != 1 || assumps[0] == !first_olit);
                         // already minimal
                         dec_lb_id
                     } else {
-                        let proof = self
-                            .oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        let proof = proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut();
 
                         let start = if assumps[0] == !first_olit { 1 } else { 0 };
                         let mut implications = Vec::with_capacity(assumps.len());
                         let mut val = encodings[0].next_higher(encodings[0].next_higher(inc_cost));
                         for &a in &assumps[start..] {
-                            let (olit, sems) = encodings[0].output_proof_details(val);
+                            let (olit, sems) = encodings[0].output_proof_details(val)?;
                             debug_assert_eq!(a, !olit);
                             let implication = proof.operations::<Var>(
                                 &((OperationSequence::from(first_sems.if_def.unwrap())
@@ -555,14 +677,14 @@ where
                         proof_stuff,
                         &mut self.oracle,
                     )?;
-                    let proof = self
-                        .oracle
-                        .proof_tracer_mut(&proof_stuff.pt_handle)
+                    let proof = proof_stuff
+                        .pt_handle
+                        .tracer_mut(&mut self.oracle)
                         .proof_mut();
                     let mut implications = Vec::with_capacity(assumps.len());
                     let mut val = inc_cost + 1;
                     for &a in &assumps {
-                        let (olit, sems) = encodings[0].output_proof_details(val);
+                        let (olit, sems) = encodings[0].output_proof_details(val)?;
                         let clause = proofs::LbConstraint::clause([
                             !proofs::AnyVar::Solver(a.var()).axiom(a.is_neg()),
                             ideal_lit,
@@ -603,9 +725,9 @@ where
                     Some(shortened)
                 };
 
-                let proof = self
-                    .oracle
-                    .proof_tracer_mut(&proof_stuff.pt_handle)
+                let proof = proof_stuff
+                    .pt_handle
+                    .tracer_mut(&mut self.oracle)
                     .proof_mut();
 
                 // derive cut that will be added
@@ -622,7 +744,7 @@ where
                         #[cfg(feature = "verbose-proofs")]
                         {
                             if encodings[0].is_buffer_empty() {
-                                let (olit, _) = encodings[0].output_proof_details(inc_cost);
+                                let (olit, _) = encodings[0].output_proof_details(inc_cost)?;
                                 proof.comment(&"here")?;
                                 proof.equals(
                                     &pidgeons::Axiom::from(olit),
@@ -639,7 +761,7 @@ where
                 #[cfg(feature = "verbose-proofs")]
                 {
                     if encodings[1].is_buffer_empty() {
-                        let (olit, _) = encodings[1].output_proof_details(dec_cost);
+                        let (olit, _) = encodings[1].output_proof_details(dec_cost)?;
                         proof.equals(
                             &pidgeons::Axiom::from(!olit),
                             Some(pidgeons::ConstraintId::from(cut_id)),
@@ -652,20 +774,20 @@ where
                 let assumps = encodings[1].enforce_ub(dec_cost - 1)?;
 
                 if encodings[1].is_buffer_empty() {
-                    let (first_olit, first_sems) = encodings[1].output_proof_details(dec_cost);
+                    let (first_olit, first_sems) = encodings[1].output_proof_details(dec_cost)?;
                     debug_assert_eq!(!first_olit, assumps[0]);
-                    CadicalCertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
+                    CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
                         .add_cert_clause(clause![assumps[0]], cut_id)?;
 
                     let mut val = dec_cost;
                     for &a in &assumps[1..] {
                         val = encodings[1].next_higher(val);
                         // first convince veripb that `olit -> first_olit`
-                        let (olit, sems) = encodings[1].output_proof_details(val);
+                        let (olit, sems) = encodings[1].output_proof_details(val)?;
                         debug_assert_eq!(!olit, a);
-                        let proof = self
-                            .oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        let proof = proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut();
                         let implication = proof.operations::<Var>(
                             &((OperationSequence::from(first_sems.if_def.unwrap())
@@ -680,10 +802,11 @@ where
                         )?;
                         let id = proof
                             .operations::<Var>(&(OperationSequence::from(cut_id) + implication))?;
-                        CadicalCertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
+                        CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
                             .add_cert_clause(clause![a], id)?;
-                        self.oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut()
                             .delete_ids::<Var, Clause, _, _>(
                                 [ConstraintId::from(implication)],
@@ -699,15 +822,15 @@ where
                     )?;
                     let mut val = dec_cost;
                     for &a in &assumps {
-                        let proof = self
-                            .oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        let proof = proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut();
                         let clause = proofs::LbConstraint::clause([
                             ideal_lit,
                             proofs::AnyVar::Solver(a.var()).axiom(a.is_neg()),
                         ]);
-                        let (olit, sems) = encodings[1].output_proof_details(val);
+                        let (olit, sems) = encodings[1].output_proof_details(val)?;
                         let implication = if a.var() < olit.var() {
                             debug_assert!(a.var() <= self.var_manager.max_enc_var());
                             // this is an input literal with weight higher than the bound
@@ -726,10 +849,11 @@ where
                         };
                         let id = proof
                             .operations::<Var>(&(OperationSequence::from(cut_id) + implication))?;
-                        CadicalCertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
+                        CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
                             .add_cert_clause(clause![a], id)?;
-                        self.oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut()
                             .delete_ids::<Var, Clause, _, _>(
                                 [ConstraintId::from(implication)],
@@ -746,6 +870,8 @@ where
             }
             inc_lb = inc_cost + 1;
             last_dec_lb_id = dec_lb_id;
+            // the point is cut off, so a termination can be raised now
+            yield_term?;
 
             (sol, inc_cost) = match self.solve_assumps(base_assumps)? {
                 SolverResult::Sat => {
@@ -761,6 +887,6 @@ where
             };
         }
         self.log_routine_end()?;
-        Done(())
+        Done(MaybeTerminated::Done(()))
     }
 }
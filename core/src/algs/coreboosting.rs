@@ -126,9 +126,10 @@ impl MergeOllRef for (DbGte, DbTotalizer) {
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
 {
     /// Performs core boosting on the instance by executing single-objective OLL
@@ -152,7 +153,7 @@ where
             }
 
             if let Some(super::proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-                let proof = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                let proof = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
                 // check that the reformulation is correct
                 #[cfg(feature = "verbose-proofs")]
                 if let Some(reform_id) = reform.reform_id {
@@ -242,8 +243,11 @@ where
     where
         (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
     {
-        debug_assert!(self.proof_stuff.is_none());
-
+        // MaxPre does not log its techniques to the proof
+        ensure!(
+            self.proof_stuff.is_none(),
+            "inprocessing is not supported with proof logging"
+        );
         ensure!(
             self.opts.store_cnf,
             "cannot reset oracle without having stored the CNF"
@@ -2,7 +2,6 @@
 
 use std::io;
 
-use cadical_veripb_tracer::CadicalCertCollector;
 use pidgeons::{AbsConstraintId, ConstraintId, OperationSequence};
 use rustsat::{
     encodings::{
@@ -11,18 +10,20 @@ use rustsat::{
     },
     instances::ManageVars,
     solvers::{
-        LimitConflicts, Solve, SolveIncremental,
+        LimitConflicts, Solve, SolveIncremental, SolveStats,
         SolverResult::{Interrupted, Sat, Unsat},
     },
     types::{Assignment, Clause, Lit, RsHashMap, RsHashSet, Var},
 };
 
+use scuttle_proc::oracle_bounds;
+
 use crate::{
     algs::proofs,
     MaybeTerminatedError::{self, Done},
 };
 
-use super::{Kernel, Objective};
+use super::{CertCollector, Kernel, Objective};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReformData {
@@ -222,9 +223,10 @@ struct CoreData {
     proof_id: Option<pidgeons::AbsConstraintId>,
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
 {
     /// OLL core-guided search over an objective. The implementation includes the following
@@ -308,7 +310,7 @@ where
                         }
 
                         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-                            let proof = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                            let proof = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
                             if exact_reformulation {
                                 // extend reformulation ids to include _all_ totalizer outputs
                                 for ReformData {
@@ -444,7 +446,7 @@ where
 
                     let mut core_id =
                         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-                            let core_id = self.oracle.proof_tracer_mut(pt_handle).core_id();
+                            let core_id = pt_handle.tracer_mut(&mut self.oracle).core_id();
                             debug_assert!(core_id.is_some());
                             core_id
                         } else {
@@ -502,7 +504,7 @@ where
                                 let proof_id = proof_id.expect(
                                     "expected a reformulation proof id while proof logging",
                                 );
-                                let proof = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                                let proof = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
                                 #[cfg(feature = "verbose-proofs")]
                                 proof.comment(&format_args!(
                                     "extending core reformulation {root} from oidx {oidx}"
@@ -580,8 +582,8 @@ where
                     .only_if_def
                     .unwrap();
                 Some(
-                    self.oracle
-                        .proof_tracer_mut(pt_handle)
+                    pt_handle
+                        .tracer_mut(&mut self.oracle)
                         .proof_mut()
                         .operations::<Var>(&((proof_reform.unwrap() + only_if_def) / 2))?,
                 )
@@ -628,7 +630,7 @@ where
                 let proof_reform = proof_reform
                     .as_mut()
                     .expect("expected reformulation while proof logging");
-                let core_id = self.oracle.proof_tracer_mut(pt_handle).core_id().unwrap();
+                let core_id = pt_handle.tracer_mut(&mut self.oracle).core_id().unwrap();
                 *proof_reform += core_id;
             }
 
@@ -641,7 +643,7 @@ where
         let proof_id = if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
             // Write the reformulation to the proof
             let proof_reform = proof_reform.expect("expected reformulation while proof logging");
-            let proof = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
             #[cfg(feature = "verbose-proofs")]
             proof.comment(&format_args!(
                 "core reformulation from core exhaustion up to bound {bound}"
@@ -708,7 +710,7 @@ where
                     });
                 }
                 proof_id = if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-                    let core_id = self.oracle.proof_tracer_mut(pt_handle).core_id();
+                    let core_id = pt_handle.tracer_mut(&mut self.oracle).core_id();
                     debug_assert!(core_id.is_some());
                     core_id
                 } else {
@@ -765,7 +767,7 @@ where
                 });
             }
             proof_id = if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-                let core_id = self.oracle.proof_tracer_mut(pt_handle).core_id();
+                let core_id = pt_handle.tracer_mut(&mut self.oracle).core_id();
                 debug_assert!(core_id.is_some());
                 core_id
             } else {
@@ -790,13 +792,13 @@ where
         tot_db: &mut TotDb,
     ) -> anyhow::Result<Lit> {
         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-            let proof: *mut _ = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof: *mut _ = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
             #[cfg(feature = "verbose-proofs")]
             {
                 unsafe { &mut *proof }
                     .comment(&format_args!("extending totalizer {root} to {oidx}"))?;
             }
-            let mut collector = CadicalCertCollector::new(&mut self.oracle, pt_handle);
+            let mut collector = CertCollector::new(&mut self.oracle, pt_handle);
             let mut leafs = vec![rustsat::lit![0]; tot_db[root].n_leafs()];
             tot_db
                 .define_unweighted_cert(
@@ -0,0 +1,663 @@
+//! # Divide and Conquer Multi-Objective Optimization
+//!
+//! This module implements a divide and conquer approach to computing the
+//! Pareto front. The algorithm computes the ideal point of the (sub)problem,
+//! fixes one objective at a time to its ideal value and recursively solves the
+//! subproblem over the remaining objectives. Once a subproblem is small enough,
+//! it is solved with the configured recursion anchor (see
+//! [`DivConAnchor`](crate::options::DivConAnchor)). Every non-dominated point
+//! that is found is permanently blocked together with all solutions it weakly
+//! dominates, which ensures that all points found in later subproblems are
+//! non-dominated in the entire problem.
+
+use std::{fs, io, mem};
+
+use pidgeons::ConstraintId;
+use rustsat::{
+    encodings::{
+        self,
+        card::{self, DbTotalizer},
+        pb::{self, DbGte},
+        CollectCertClauses,
+    },
+    solvers::{
+        DefaultInitializer, Initialize, Solve, SolveIncremental, SolveStats, SolverResult,
+        SolverStats,
+    },
+    types::{Assignment, Clause, Lit, Var, WLitIter},
+};
+use scuttle_proc::{oracle_bounds, KernelFunctions};
+
+use crate::{
+    options::{AfterCbOptions, BuildEncodings, CoreBoostingOptions, DivConAnchor, DivConOptions},
+    termination::ensure,
+    types::{Constraint, ParetoFront, VarManager},
+    EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits,
+    MaybeTerminatedError::{self, Done},
+};
+
+use super::{
+    coreboosting::MergeOllRef, lowerbounding::Fence, proofs, CertCollector, CoreBoost, Kernel,
+    ObjEncoding, Objective,
+};
+
+/// The divide and conquer algorithm type
+///
+/// # Generics
+///
+/// - `O`: the SAT solver oracle
+/// - `PBE`: pseudo-Boolean objective encoding
+/// - `CE`: cardinality objective encoding
+/// - `ProofW`: the proof writer
+/// - `OInit`: the oracle initializer
+/// - `BCG`: the blocking clause generator
+#[derive(KernelFunctions)]
+pub struct DivCon<
+    O,
+    PBE = DbGte,
+    CE = DbTotalizer,
+    ProofW = io::BufWriter<fs::File>,
+    OInit = DefaultInitializer,
+    BCG = fn(Assignment) -> Clause,
+> where
+    ProofW: io::Write,
+{
+    /// The solver kernel
+    kernel: Kernel<O, ProofW, OInit, BCG>,
+    /// A cardinality or pseudo-boolean encoding for each objective
+    obj_encs: Vec<ObjEncoding<PBE, CE>>,
+    /// Divide and conquer specific options
+    opts: DivConOptions,
+    /// The Pareto front discovered so far
+    pareto_front: ParetoFront,
+}
+
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> super::Solve for DivCon<O, DbGte, DbTotalizer, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    BCG: Fn(Assignment) -> Clause,
+    ProofW: io::Write + 'static,
+    OInit: Initialize<O>,
+{
+    fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
+        ensure!(
+            self.kernel.proof_stuff.is_none() || self.opts.build_encodings == BuildEncodings::Once,
+            "rebuilding encodings is not supported with proof logging"
+        );
+        if let DivConAnchor::PMinimal(sub_size) | DivConAnchor::LowerBounding(sub_size) =
+            self.opts.anchor
+        {
+            ensure!(
+                sub_size
+                    .checked_absolute(self.kernel.stats.n_objs)
+                    .is_some(),
+                "the anchor subproblem size {} is invalid for {} objectives",
+                sub_size,
+                self.kernel.stats.n_objs
+            );
+        }
+        self.kernel.start_solving(limits);
+        self.kernel.attach_deadlines();
+        let res = self.alg_main();
+        self.kernel.end_solving(res)
+    }
+
+    fn all_stats(
+        &self,
+    ) -> (
+        crate::Stats,
+        Option<SolverStats>,
+        Option<Vec<EncodingStats>>,
+    ) {
+        use crate::ExtendedSolveStats;
+        (
+            self.kernel.stats,
+            Some(self.oracle_stats()),
+            Some(self.encoding_stats()),
+        )
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Init for DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental,
+    ProofW: io::Write,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    OInit: Initialize<O>,
+    BCG: Fn(Assignment) -> Clause,
+{
+    type Oracle = O;
+    type BlockClauseGen = BCG;
+
+    /// Initializes a default solver with a configured oracle and options. The
+    /// oracle should _not_ have any clauses loaded yet.
+    fn new<Cls, Objs, Obj>(
+        clauses: Cls,
+        objs: Objs,
+        var_manager: VarManager,
+        opts: KernelOptions,
+        block_clause_gen: BCG,
+    ) -> anyhow::Result<Self>
+    where
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
+        Objs: IntoIterator<Item = (Obj, isize)>,
+        Obj: WLitIter,
+    {
+        let kernel = Kernel::new(clauses, objs, var_manager, block_clause_gen, opts)?;
+        Ok(Self::init(kernel))
+    }
+}
+
+impl<'term, 'learn, PBE, CE, ProofW, OInit, BCG> super::InitCert
+    for DivCon<rustsat_cadical::CaDiCaL<'term, 'learn>, PBE, CE, ProofW, OInit, BCG>
+where
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    OInit: Initialize<rustsat_cadical::CaDiCaL<'term, 'learn>>,
+    ProofW: io::Write + 'static,
+    BCG: Fn(Assignment) -> Clause,
+{
+    type ProofWriter = ProofW;
+
+    /// Initializes a default solver with a configured oracle and options. The
+    /// oracle should _not_ have any clauses loaded yet.
+    fn new_cert<Cls, Objs, Obj>(
+        clauses: Cls,
+        objs: Objs,
+        var_manager: VarManager,
+        opts: KernelOptions,
+        proof: pidgeons::Proof<Self::ProofWriter>,
+        block_clause_gen: BCG,
+    ) -> anyhow::Result<Self>
+    where
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
+        Objs: IntoIterator<Item = (Obj, isize)>,
+        Obj: WLitIter,
+    {
+        let kernel = Kernel::new_cert(clauses, objs, var_manager, block_clause_gen, proof, opts)?;
+        Ok(Self::init(kernel))
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> ExtendedSolveStats for DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveStats,
+    ProofW: io::Write,
+    PBE: encodings::EncodeStats,
+    CE: encodings::EncodeStats,
+{
+    fn oracle_stats(&self) -> SolverStats {
+        self.kernel.oracle.stats()
+    }
+
+    fn encoding_stats(&self) -> Vec<EncodingStats> {
+        self.kernel
+            .objs
+            .iter()
+            .zip(self.obj_encs.iter())
+            .map(|(obj, enc)| {
+                let mut s = EncodingStats {
+                    offset: obj.offset(),
+                    ..Default::default()
+                };
+                if let Objective::Unweighted { unit_weight, .. } = obj {
+                    s.unit_weight = Some(*unit_weight);
+                };
+                match enc {
+                    ObjEncoding::Weighted(enc, _) => {
+                        s.n_vars = enc.n_vars();
+                        s.n_clauses = enc.n_clauses()
+                    }
+                    ObjEncoding::Unweighted(enc, _) => {
+                        s.n_vars = enc.n_vars();
+                        s.n_clauses = enc.n_clauses()
+                    }
+                    ObjEncoding::Constant => (),
+                };
+                s
+            })
+            .collect()
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+{
+    /// Initializes the solver
+    fn init(mut kernel: Kernel<O, ProofW, OInit, BCG>) -> Self {
+        let obj_encs = Self::build_encodings(&mut kernel);
+        Self {
+            kernel,
+            obj_encs,
+            opts: DivConOptions::default(),
+            pareto_front: Default::default(),
+        }
+    }
+
+    /// Builds fresh encodings for all objectives
+    fn build_encodings(kernel: &mut Kernel<O, ProofW, OInit, BCG>) -> Vec<ObjEncoding<PBE, CE>> {
+        kernel
+            .objs
+            .iter()
+            .map(|obj| match obj {
+                Objective::Weighted { lits, .. } => ObjEncoding::new_weighted(
+                    lits.iter().map(|(&l, &w)| (l, w)),
+                    kernel.opts.reserve_enc_vars,
+                    &mut kernel.var_manager,
+                ),
+                Objective::Unweighted { lits, .. } => ObjEncoding::new_unweighted(
+                    lits.iter().copied(),
+                    kernel.opts.reserve_enc_vars,
+                    &mut kernel.var_manager,
+                ),
+                Objective::Constant { .. } => ObjEncoding::Constant,
+            })
+            .collect()
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    /// Sets the divide and conquer specific options
+    pub fn set_options(&mut self, opts: DivConOptions) {
+        self.opts = opts;
+    }
+}
+
+/// The algorithm that a subproblem is solved with
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Anchor {
+    LinSu,
+    BiOptSat,
+    PMinimal,
+    LowerBounding,
+}
+
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> DivCon<O, DbGte, DbTotalizer, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    BCG: Fn(Assignment) -> Clause,
+    ProofW: io::Write + 'static,
+    OInit: Initialize<O>,
+{
+    /// The solving algorithm main routine.
+    fn alg_main(&mut self) -> MaybeTerminatedError {
+        debug_assert_eq!(self.obj_encs.len(), self.kernel.stats.n_objs);
+        self.kernel.log_routine_start("divide and conquer")?;
+        let all_objs: Vec<_> = (0..self.kernel.stats.n_objs).collect();
+        self.solve_subproblem(vec![0; all_objs.len()], &[], &all_objs)?;
+        self.kernel.log_routine_end()?;
+        Done(())
+    }
+
+    /// Recursively solves the subproblem over the given objectives. All other
+    /// objectives are fixed by the base assumptions.
+    fn solve_subproblem(
+        &mut self,
+        mut ideal: Vec<usize>,
+        base_assumps: &[Lit],
+        obj_idxs: &[usize],
+    ) -> MaybeTerminatedError {
+        debug_assert_eq!(ideal.len(), self.kernel.stats.n_objs);
+        debug_assert!(!obj_idxs.is_empty());
+        if let Some(anchor) = self.anchor(obj_idxs.len()) {
+            return self.solve_anchor(anchor, base_assumps, obj_idxs);
+        }
+        let top_level = obj_idxs.len() == self.kernel.stats.n_objs;
+        let mut first = true;
+        loop {
+            if !self.find_ideal(base_assumps, obj_idxs, &mut ideal)? {
+                return Done(());
+            }
+            if top_level && !first {
+                self.rebuild_encodings()?;
+            }
+            first = false;
+            for &oidx in obj_idxs {
+                // Fix objective to its ideal value and recurse on the remaining objectives
+                self.kernel
+                    .extend_encoding(&mut self.obj_encs[oidx], ideal[oidx]..ideal[oidx] + 1)?;
+                let mut assumps = Vec::from(base_assumps);
+                assumps.extend(self.obj_encs[oidx].enforce_ub(ideal[oidx]).unwrap());
+                let sub_idxs: Vec<_> = obj_idxs
+                    .iter()
+                    .copied()
+                    .filter(|&idx| idx != oidx)
+                    .collect();
+                self.solve_subproblem(ideal.clone(), &assumps, &sub_idxs)?;
+            }
+        }
+    }
+
+    /// Determines whether a subproblem of the given size is solved directly
+    /// and if so, with which algorithm
+    fn anchor(&self, size: usize) -> Option<Anchor> {
+        let n_objs = self.kernel.stats.n_objs;
+        if size == 1 {
+            return Some(Anchor::LinSu);
+        }
+        // BiOptSat does not support base assumptions with proof logging
+        let bioptsat = if self.kernel.proof_stuff.is_some() && size < n_objs {
+            Anchor::PMinimal
+        } else {
+            Anchor::BiOptSat
+        };
+        match self.opts.anchor {
+            DivConAnchor::LinSu => None,
+            DivConAnchor::BiOptSat => (size == 2).then_some(bioptsat),
+            DivConAnchor::PMinimal(sub_size) => sub_size
+                .checked_absolute(n_objs)
+                .is_some_and(|abs| size <= abs)
+                .then_some(Anchor::PMinimal),
+            DivConAnchor::LowerBounding(sub_size) => sub_size
+                .checked_absolute(n_objs)
+                .is_some_and(|abs| size <= abs)
+                .then_some(Anchor::LowerBounding),
+            DivConAnchor::NMinus(x) => {
+                if size + x > n_objs {
+                    None
+                } else if size == 2 {
+                    Some(bioptsat)
+                } else {
+                    Some(Anchor::PMinimal)
+                }
+            }
+        }
+    }
+
+    /// Solves a subproblem with a recursion anchor
+    fn solve_anchor(
+        &mut self,
+        anchor: Anchor,
+        base_assumps: &[Lit],
+        obj_idxs: &[usize],
+    ) -> MaybeTerminatedError {
+        match anchor {
+            Anchor::LinSu => {
+                debug_assert_eq!(obj_idxs.len(), 1);
+                let oidx = obj_idxs[0];
+                let n_found = self.pareto_front.len();
+                let res = self.kernel.linsu_yield(
+                    oidx,
+                    &mut self.obj_encs[oidx],
+                    base_assumps,
+                    None,
+                    None,
+                    &mut self.pareto_front,
+                );
+                // Block before checking the result to not lose points on termination
+                self.block_found(n_found)?;
+                if let Some((_, _, _, yield_term)) = res? {
+                    yield_term?;
+                }
+                Done(())
+            }
+            Anchor::BiOptSat => {
+                debug_assert_eq!(obj_idxs.len(), 2);
+                let (inc, dec) = (obj_idxs[0], obj_idxs[1]);
+                let n_found = self.pareto_front.len();
+                let mut encs = [
+                    mem::replace(&mut self.obj_encs[inc], ObjEncoding::Constant),
+                    mem::replace(&mut self.obj_encs[dec], ObjEncoding::Constant),
+                ];
+                let res = self.kernel.bioptsat(
+                    (inc, dec),
+                    &mut encs,
+                    base_assumps,
+                    None,
+                    (None, None),
+                    |_| None,
+                    &mut self.pareto_front,
+                );
+                let [inc_enc, dec_enc] = encs;
+                self.obj_encs[inc] = inc_enc;
+                self.obj_encs[dec] = dec_enc;
+                self.block_found(n_found)?;
+                let yield_term = res?;
+                yield_term?;
+                Done(())
+            }
+            Anchor::PMinimal => self.kernel.harvest(
+                &Fence { data: vec![] },
+                &mut self.obj_encs,
+                base_assumps,
+                None,
+                &mut self.pareto_front,
+            ),
+            Anchor::LowerBounding => self.lower_bounding(base_assumps, obj_idxs),
+        }
+    }
+
+    /// Runs lower-bounding search on a subproblem. The fence only covers the
+    /// objectives of the subproblem.
+    fn lower_bounding(&mut self, base_assumps: &[Lit], obj_idxs: &[usize]) -> MaybeTerminatedError {
+        self.kernel.log_routine_start("lower-bounding")?;
+        let mut fence = Fence {
+            data: Vec::with_capacity(self.obj_encs.len()),
+        };
+        for (oidx, enc) in self.obj_encs.iter_mut().enumerate() {
+            if !obj_idxs.contains(&oidx) {
+                fence.data.push((enc.offset(), vec![]));
+                continue;
+            }
+            let bound = enc.offset();
+            self.kernel.extend_encoding(enc, bound..bound + 1)?;
+            fence.data.push((bound, enc.enforce_ub(bound).unwrap()));
+        }
+        loop {
+            let mut assumps = Vec::from(base_assumps);
+            assumps.extend(fence.assumps());
+            match self.kernel.solve_assumps(&assumps)? {
+                SolverResult::Sat => self.kernel.harvest(
+                    &fence,
+                    &mut self.obj_encs,
+                    base_assumps,
+                    None,
+                    &mut self.pareto_front,
+                )?,
+                SolverResult::Unsat => {
+                    let core = self.kernel.oracle.core()?;
+                    if !core
+                        .iter()
+                        .any(|&clit| fence.assumps().any(|alit| !alit == clit))
+                    {
+                        // Subproblem is exhausted
+                        self.kernel.log_routine_end()?;
+                        return Done(());
+                    }
+                    self.kernel
+                        .update_fence(&mut fence, core, &mut self.obj_encs)?;
+                }
+                SolverResult::Interrupted => panic!("should have errored before"),
+            }
+        }
+    }
+
+    /// Computes the ideal point of the subproblem over the given objectives.
+    /// Returns `false` if the subproblem is unsatisfiable.
+    fn find_ideal(
+        &mut self,
+        base_assumps: &[Lit],
+        obj_idxs: &[usize],
+        ideal: &mut [usize],
+    ) -> MaybeTerminatedError<bool> {
+        self.kernel.log_routine_start("find ideal")?;
+        for &oidx in obj_idxs {
+            let Some((cost, _, _)) =
+                self.kernel
+                    .linsu(oidx, &mut self.obj_encs[oidx], base_assumps, None, None)?
+            else {
+                self.kernel.log_routine_end()?;
+                return Done(false);
+            };
+            ideal[oidx] = cost;
+        }
+        if let Some(logger) = &mut self.kernel.logger {
+            logger.log_ideal(ideal)?;
+        }
+        self.kernel.log_routine_end()?;
+        Done(true)
+    }
+
+    /// Rebuilds the objective encodings according to the options
+    fn rebuild_encodings(&mut self) -> MaybeTerminatedError {
+        match self.opts.build_encodings {
+            BuildEncodings::Once => return Done(()),
+            BuildEncodings::Rebuild => (),
+            BuildEncodings::CleanRebuild => {
+                self.kernel.reset_oracle(true)?;
+                self.kernel.attach_deadlines();
+            }
+        }
+        self.kernel.log_routine_start("rebuild encodings")?;
+        self.obj_encs = Self::build_encodings(&mut self.kernel);
+        if self.opts.build_encodings == BuildEncodings::CleanRebuild {
+            // The fresh oracle does not know about the points found so far
+            self.block_found(0)?;
+        }
+        self.kernel.log_routine_end()?;
+        Done(())
+    }
+
+    /// Permanently blocks all solutions that are weakly dominated by a
+    /// non-dominated point found since the given index in the Pareto front
+    fn block_found(&mut self, since: usize) -> MaybeTerminatedError {
+        for idx in since..self.pareto_front.len() {
+            let mut witness = self.pareto_front[idx].iter().next().unwrap().clone();
+            let costs = (0..self.kernel.stats.n_objs)
+                .map(|oidx| {
+                    self.kernel
+                        .get_cost_with_heuristic_improvements(oidx, &mut witness, false)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let (block_clause, reification_ids) = self
+                .kernel
+                .dominated_block_clause(&costs, &mut self.obj_encs)?;
+            if let Some(proof_stuff) = &mut self.kernel.proof_stuff {
+                let cut_id = proofs::certify_pmin_cut(
+                    &self.obj_encs,
+                    &self.kernel.objs,
+                    &costs,
+                    &witness,
+                    self.kernel.var_manager.max_enc_var(),
+                    proof_stuff,
+                    &mut self.kernel.oracle,
+                )?;
+                let proof = proof_stuff
+                    .pt_handle
+                    .tracer_mut(&mut self.kernel.oracle)
+                    .proof_mut();
+                let clause_id = proof.reverse_unit_prop(
+                    &block_clause,
+                    reification_ids
+                        .into_iter()
+                        .chain([cut_id])
+                        .map(ConstraintId::from),
+                )?;
+                let mut collector =
+                    CertCollector::new(&mut self.kernel.oracle, &proof_stuff.pt_handle);
+                collector.add_cert_clause(block_clause, clause_id)?;
+            } else {
+                self.kernel.oracle.add_clause(block_clause)?;
+            }
+        }
+        Done(())
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> CoreBoost for DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool> {
+        ensure!(
+            self.kernel.stats.n_solve_calls == 0,
+            "cannot perform core boosting after solve has been called"
+        );
+        self.kernel.start_core_boosting(&limits);
+        let res = self.core_boost_main(opts);
+        self.kernel.detach_deadlines();
+        res
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> DivCon<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    /// Performs core boosting and merges the OLL reformulations into the
+    /// objective encodings
+    fn core_boost_main(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+        let Some(cb_res) = self.kernel.core_boost()? else {
+            return Done(false);
+        };
+        self.kernel.check_termination()?;
+        let reset_dbs = match &opts.after {
+            AfterCbOptions::Nothing => false,
+            AfterCbOptions::Reset => {
+                self.kernel.reset_oracle(true)?;
+                self.kernel.check_termination()?;
+                true
+            }
+            AfterCbOptions::Inpro(techs) => {
+                self.obj_encs = self.kernel.inprocess(techs, cb_res)?;
+                self.kernel.check_termination()?;
+                return Done(true);
+            }
+        };
+        self.kernel.log_routine_start("merge encodings")?;
+        for (oidx, (reform, mut tot_db)) in cb_res.into_iter().enumerate() {
+            if reset_dbs {
+                debug_assert!(self.kernel.proof_stuff.is_none());
+                tot_db.reset_vars();
+            }
+            if !matches!(self.kernel.objs[oidx], Objective::Constant { .. }) {
+                if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
+                    if !reform.reformulations.is_empty() {
+                        // delete remaining reformulation constraints from proof
+                        let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
+                        #[cfg(feature = "verbose-proofs")]
+                        proof.comment(&format_args!(
+                            "deleting remaining reformulation constraints from OLL of objective {oidx}"
+                        ))?;
+                        proof.delete_ids::<Var, Clause, _, _>(
+                            reform
+                                .reformulations
+                                .values()
+                                .map(|re| ConstraintId::from(re.proof_id.unwrap())),
+                            None,
+                        )?;
+                    }
+                }
+
+                self.obj_encs[oidx] = <(PBE, CE)>::merge(reform, tot_db, opts.rebase);
+            }
+            self.kernel.check_termination()?;
+        }
+        self.kernel.log_routine_end()?;
+        Done(true)
+    }
+}
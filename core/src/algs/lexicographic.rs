@@ -0,0 +1,465 @@
+//! # Lexicographic Multi-Objective Optimization
+//!
+//! This module implements lexicographic optimization for instances where the
+//! objectives have a priority order. Instead of the entire Pareto front, only
+//! the lexicographically optimal non-dominated point is computed. The
+//! objectives are optimized one after another, either with linear sat-unsat
+//! search or with core-guided OLL search, and the optimum of each objective is
+//! fixed before moving on to the next one.
+
+use std::{fs, io};
+
+use pidgeons::ConstraintId;
+use rustsat::{
+    encodings::{
+        self,
+        card::{self, DbTotalizer},
+        pb::{self, DbGte},
+        totdb::Db as TotDb,
+    },
+    solvers::{
+        DefaultInitializer, Initialize, Solve, SolveIncremental, SolveStats, SolverResult,
+        SolverStats,
+    },
+    types::{Assignment, Clause, Lit, Var, WLitIter},
+};
+use scuttle_proc::{oracle_bounds, KernelFunctions};
+
+use crate::{
+    options::{AfterCbOptions, CoreBoostingOptions, LexicographicOptions},
+    termination::ensure,
+    types::{Constraint, ParetoFront, VarManager},
+    EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits,
+    MaybeTerminatedError::{self, Done},
+};
+
+use super::{
+    coreboosting::MergeOllRef, coreguided::OllReformulation, proofs, CoreBoost, Kernel,
+    ObjEncoding, Objective,
+};
+
+/// The lexicographic algorithm type
+///
+/// # Generics
+///
+/// - `O`: the SAT solver oracle
+/// - `PBE`: pseudo-Boolean objective encoding
+/// - `CE`: cardinality objective encoding
+/// - `ProofW`: the proof writer
+/// - `OInit`: the oracle initializer
+/// - `BCG`: the blocking clause generator
+#[derive(KernelFunctions)]
+pub struct Lexicographic<
+    O,
+    PBE = DbGte,
+    CE = DbTotalizer,
+    ProofW = io::BufWriter<fs::File>,
+    OInit = DefaultInitializer,
+    BCG = fn(Assignment) -> Clause,
+> where
+    ProofW: io::Write,
+{
+    /// The solver kernel
+    kernel: Kernel<O, ProofW, OInit, BCG>,
+    /// A cardinality or pseudo-boolean encoding for each objective
+    obj_encs: Vec<ObjEncoding<PBE, CE>>,
+    /// Lexicographic specific options
+    opts: LexicographicOptions,
+    /// The Pareto front discovered so far
+    pareto_front: ParetoFront,
+}
+
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> super::Solve
+    for Lexicographic<O, DbGte, DbTotalizer, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    BCG: Fn(Assignment) -> Clause,
+    ProofW: io::Write + 'static,
+{
+    fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
+        let n_objs = self.kernel.stats.n_objs;
+        ensure!(
+            self.opts.priority.iter().all(|&idx| idx < n_objs),
+            "objective priority contains an index out of range"
+        );
+        ensure!(
+            self.opts
+                .priority
+                .iter()
+                .enumerate()
+                .all(|(pos, idx)| !self.opts.priority[..pos].contains(idx)),
+            "objective priority contains an objective more than once"
+        );
+        self.kernel.start_solving(limits);
+        self.kernel.attach_deadlines();
+        let res = self.alg_main();
+        // only the lexicographic optimum is found, not the entire Pareto front
+        self.kernel.end_partial_solving(res)
+    }
+
+    fn all_stats(
+        &self,
+    ) -> (
+        crate::Stats,
+        Option<SolverStats>,
+        Option<Vec<EncodingStats>>,
+    ) {
+        use crate::ExtendedSolveStats;
+        (
+            self.kernel.stats,
+            Some(self.oracle_stats()),
+            Some(self.encoding_stats()),
+        )
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Init for Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental,
+    ProofW: io::Write,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    OInit: Initialize<O>,
+    BCG: Fn(Assignment) -> Clause,
+{
+    type Oracle = O;
+    type BlockClauseGen = BCG;
+
+    /// Initializes a default solver with a configured oracle and options. The
+    /// oracle should _not_ have any clauses loaded yet.
+    fn new<Cls, Objs, Obj>(
+        clauses: Cls,
+        objs: Objs,
+        var_manager: VarManager,
+        opts: KernelOptions,
+        block_clause_gen: BCG,
+    ) -> anyhow::Result<Self>
+    where
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
+        Objs: IntoIterator<Item = (Obj, isize)>,
+        Obj: WLitIter,
+    {
+        let kernel = Kernel::new(clauses, objs, var_manager, block_clause_gen, opts)?;
+        Ok(Self::init(kernel))
+    }
+}
+
+impl<'term, 'learn, PBE, CE, ProofW, OInit, BCG> super::InitCert
+    for Lexicographic<rustsat_cadical::CaDiCaL<'term, 'learn>, PBE, CE, ProofW, OInit, BCG>
+where
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    OInit: Initialize<rustsat_cadical::CaDiCaL<'term, 'learn>>,
+    ProofW: io::Write + 'static,
+    BCG: Fn(Assignment) -> Clause,
+{
+    type ProofWriter = ProofW;
+
+    /// Initializes a default solver with a configured oracle and options. The
+    /// oracle should _not_ have any clauses loaded yet.
+    fn new_cert<Cls, Objs, Obj>(
+        clauses: Cls,
+        objs: Objs,
+        var_manager: VarManager,
+        opts: KernelOptions,
+        mut proof: pidgeons::Proof<Self::ProofWriter>,
+        block_clause_gen: BCG,
+    ) -> anyhow::Result<Self>
+    where
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
+        Objs: IntoIterator<Item = (Obj, isize)>,
+        Obj: WLitIter,
+    {
+        // the lexicographic optimum does not prove the Pareto front, so the proof never concludes
+        // unsatisfiability, even if solving fails before the run is concluded
+        proof.update_default_conclusion::<Var>(
+            pidgeons::OutputGuarantee::None,
+            &pidgeons::Conclusion::None,
+        );
+        let kernel = Kernel::new_cert(clauses, objs, var_manager, block_clause_gen, proof, opts)?;
+        Ok(Self::init(kernel))
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> ExtendedSolveStats
+    for Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveStats,
+    ProofW: io::Write,
+    PBE: encodings::EncodeStats,
+    CE: encodings::EncodeStats,
+{
+    fn oracle_stats(&self) -> SolverStats {
+        self.kernel.oracle.stats()
+    }
+
+    fn encoding_stats(&self) -> Vec<EncodingStats> {
+        self.kernel
+            .objs
+            .iter()
+            .zip(self.obj_encs.iter())
+            .map(|(obj, enc)| {
+                let mut s = EncodingStats {
+                    offset: obj.offset(),
+                    ..Default::default()
+                };
+                if let Objective::Unweighted { unit_weight, .. } = obj {
+                    s.unit_weight = Some(*unit_weight);
+                };
+                match enc {
+                    ObjEncoding::Weighted(enc, _) => {
+                        s.n_vars = enc.n_vars();
+                        s.n_clauses = enc.n_clauses()
+                    }
+                    ObjEncoding::Unweighted(enc, _) => {
+                        s.n_vars = enc.n_vars();
+                        s.n_clauses = enc.n_clauses()
+                    }
+                    ObjEncoding::Constant => (),
+                };
+                s
+            })
+            .collect()
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+{
+    /// Initializes the solver
+    fn init(mut kernel: Kernel<O, ProofW, OInit, BCG>) -> Self {
+        // Initialize objective encodings
+        let obj_encs = kernel
+            .objs
+            .iter()
+            .map(|obj| match obj {
+                Objective::Weighted { lits, .. } => ObjEncoding::new_weighted(
+                    lits.iter().map(|(&l, &w)| (l, w)),
+                    kernel.opts.reserve_enc_vars,
+                    &mut kernel.var_manager,
+                ),
+                Objective::Unweighted { lits, .. } => ObjEncoding::new_unweighted(
+                    lits.iter().copied(),
+                    kernel.opts.reserve_enc_vars,
+                    &mut kernel.var_manager,
+                ),
+                Objective::Constant { .. } => ObjEncoding::Constant,
+            })
+            .collect();
+        Self {
+            kernel,
+            obj_encs,
+            opts: LexicographicOptions::default(),
+            pareto_front: Default::default(),
+        }
+    }
+}
+
+impl<O, PBE, CE, ProofW, OInit, BCG> Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    /// Sets the lexicographic specific options
+    pub fn set_options(&mut self, opts: LexicographicOptions) {
+        self.opts = opts;
+    }
+}
+
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Lexicographic<O, DbGte, DbTotalizer, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    BCG: Fn(Assignment) -> Clause,
+    ProofW: io::Write + 'static,
+{
+    /// The solving algorithm main routine.
+    fn alg_main(&mut self) -> MaybeTerminatedError {
+        debug_assert_eq!(self.obj_encs.len(), self.kernel.stats.n_objs);
+        if !self.pareto_front.is_empty() {
+            // the lexicographic optimum has already been found
+            return Done(());
+        }
+        self.kernel.log_routine_start("lexicographic")?;
+        let res = self.kernel.solve()?;
+        if res == SolverResult::Unsat {
+            self.kernel.log_routine_end()?;
+            return Done(());
+        }
+        let mut sol = self
+            .kernel
+            .oracle
+            .solution(self.kernel.var_manager.max_var().unwrap())?;
+        let mut assumps = vec![];
+        for oidx in self.opts.order(self.kernel.stats.n_objs) {
+            let cost = if self.opts.core_guided {
+                self.oll_stage(oidx, &assumps, &mut sol)?
+                    .expect("previous stage was satisfiable")
+            } else {
+                let ub = self
+                    .kernel
+                    .get_cost_with_heuristic_improvements(oidx, &mut sol, false)?;
+                let lb = self.obj_encs[oidx].offset();
+                let (cost, stage_sol, _) = self
+                    .kernel
+                    .linsu(
+                        oidx,
+                        &mut self.obj_encs[oidx],
+                        &assumps,
+                        Some((ub, Some(sol))),
+                        Some(lb),
+                    )?
+                    .expect("previous stage was satisfiable");
+                sol = stage_sol;
+                cost
+            };
+            // Fix the objective to its optimum before moving on
+            self.kernel
+                .extend_encoding(&mut self.obj_encs[oidx], cost..cost + 1)?;
+            assumps.extend(self.obj_encs[oidx].enforce_ub(cost).unwrap());
+            self.kernel.check_termination()?;
+        }
+        let costs = (0..self.kernel.stats.n_objs)
+            .map(|oidx| {
+                self.kernel
+                    .get_cost_with_heuristic_improvements(oidx, &mut sol, false)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let (_, yield_term) =
+            self.kernel
+                .yield_solutions(costs, &assumps, sol, &mut self.pareto_front)?;
+        self.kernel.log_routine_end()?;
+        yield_term?;
+        Done(())
+    }
+
+    /// Optimizes an objective with core-guided OLL search under the given
+    /// assumptions and replaces `sol` with an optimal solution. Returns the
+    /// optimal cost or [`None`] if the assumptions are unsatisfiable.
+    fn oll_stage(
+        &mut self,
+        oidx: usize,
+        assumps: &[Lit],
+        sol: &mut Assignment,
+    ) -> MaybeTerminatedError<Option<usize>> {
+        let mut reform = OllReformulation::from(&self.kernel.objs[oidx]);
+        let mut tot_db = TotDb::default();
+        let Some(mut stage_sol) = self.kernel.oll(&mut reform, assumps, &mut tot_db, false)? else {
+            return Done(None);
+        };
+        let cost = self
+            .kernel
+            .get_cost_with_heuristic_improvements(oidx, &mut stage_sol, false)?;
+        if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
+            if !reform.reformulations.is_empty() {
+                // the reformulation is only used for this stage
+                let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
+                #[cfg(feature = "verbose-proofs")]
+                proof.comment(&format_args!(
+                    "deleting reformulation constraints from OLL of objective {oidx}"
+                ))?;
+                proof.delete_ids::<Var, Clause, _, _>(
+                    reform
+                        .reformulations
+                        .values()
+                        .map(|re| ConstraintId::from(re.proof_id.unwrap())),
+                    None,
+                )?;
+            }
+        }
+        *sol = stage_sol;
+        Done(Some(cost))
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> CoreBoost for Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool> {
+        ensure!(
+            self.kernel.stats.n_solve_calls == 0,
+            "cannot perform core boosting after solve has been called"
+        );
+        self.kernel.start_core_boosting(&limits);
+        let res = self.core_boost_main(opts);
+        self.kernel.detach_deadlines();
+        res
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> Lexicographic<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    /// Performs core boosting and merges the OLL reformulations into the
+    /// objective encodings
+    fn core_boost_main(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+        let Some(cb_res) = self.kernel.core_boost()? else {
+            return Done(false);
+        };
+        self.kernel.check_termination()?;
+        let reset_dbs = match &opts.after {
+            AfterCbOptions::Nothing => false,
+            AfterCbOptions::Reset => {
+                self.kernel.reset_oracle(true)?;
+                self.kernel.check_termination()?;
+                true
+            }
+            AfterCbOptions::Inpro(techs) => {
+                self.obj_encs = self.kernel.inprocess(techs, cb_res)?;
+                self.kernel.check_termination()?;
+                return Done(true);
+            }
+        };
+        self.kernel.log_routine_start("merge encodings")?;
+        for (oidx, (reform, mut tot_db)) in cb_res.into_iter().enumerate() {
+            if reset_dbs {
+                debug_assert!(self.kernel.proof_stuff.is_none());
+                tot_db.reset_vars();
+            }
+            if !matches!(self.kernel.objs[oidx], Objective::Constant { .. }) {
+                if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
+                    if !reform.reformulations.is_empty() {
+                        // delete remaining reformulation constraints from proof
+                        let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
+                        #[cfg(feature = "verbose-proofs")]
+                        proof.comment(&format_args!(
+                            "deleting remaining reformulation constraints from OLL of objective {oidx}"
+                        ))?;
+                        proof.delete_ids::<Var, Clause, _, _>(
+                            reform
+                                .reformulations
+                                .values()
+                                .map(|re| ConstraintId::from(re.proof_id.unwrap())),
+                            None,
+                        )?;
+                    }
+                }
+
+                self.obj_encs[oidx] = <(PBE, CE)>::merge(reform, tot_db, opts.rebase);
+            }
+            self.kernel.check_termination()?;
+        }
+        self.kernel.log_routine_end()?;
+        Done(true)
+    }
+}
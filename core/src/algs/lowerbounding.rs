@@ -10,7 +10,6 @@
 
 use std::{fs, io};
 
-use cadical_veripb_tracer::CadicalCertCollector;
 use pidgeons::ConstraintId;
 use rustsat::{
     clause,
@@ -25,18 +24,23 @@ use rustsat::{
     },
     types::{Assignment, Clause, Lit, Var, WLitIter},
 };
-use scuttle_proc::KernelFunctions;
+use scuttle_proc::{oracle_bounds, KernelFunctions};
 
 use crate::{
+    checkpoint::{AlgState, Progress},
+    encodings::{ensure_proof_support, CertEncoding},
     options::{AfterCbOptions, CoreBoostingOptions},
     termination::ensure,
-    types::{NonDomPoint, ParetoFront, VarManager},
+    types::{Constraint, NonDomPoint, ParetoFront, SharedFront, VarManager},
     EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits,
     MaybeTerminatedError::{self, Done},
     Phase,
 };
 
-use super::{coreboosting::MergeOllRef, proofs, CoreBoost, Kernel, ObjEncoding, Objective};
+use super::{
+    coreboosting::MergeOllRef, proofs, CertCollector, Checkpoint, Cooperate, CoreBoost, Kernel,
+    ObjEncoding, Objective, WarmStart,
+};
 
 /// The lower-bounding algorithm type
 ///
@@ -69,22 +73,20 @@ pub struct LowerBounding<
     pareto_front: ParetoFront,
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG> super::Solve
-    for LowerBounding<
-        rustsat_cadical::CaDiCaL<'term, 'learn>,
-        DbGte,
-        DbTotalizer,
-        ProofW,
-        OInit,
-        BCG,
-    >
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Solve for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    CE: card::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    O: SolveIncremental + SolveStats,
     BCG: Fn(Assignment) -> Clause,
     ProofW: io::Write + 'static,
 {
     fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
         self.kernel.start_solving(limits);
-        self.alg_main()
+        self.kernel.attach_deadlines();
+        let res = self.alg_main();
+        self.kernel.end_solving(res)
     }
 
     fn all_stats(
@@ -103,21 +105,17 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG> super::Init
-    for LowerBounding<
-        rustsat_cadical::CaDiCaL<'learn, 'term>,
-        DbGte,
-        DbTotalizer,
-        ProofW,
-        OInit,
-        BCG,
-    >
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Init for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
+    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
-    OInit: Initialize<rustsat_cadical::CaDiCaL<'learn, 'term>>,
+    OInit: Initialize<O>,
     BCG: Fn(Assignment) -> Clause,
 {
-    type Oracle = rustsat_cadical::CaDiCaL<'learn, 'term>;
+    type Oracle = O;
     type BlockClauseGen = BCG;
 
     /// Initializes a default solver with a configured oracle and options. The
@@ -130,7 +128,8 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -139,16 +138,11 @@ where
     }
 }
 
-impl<'term, 'learn, ProofW, OInit, BCG> super::InitCert
-    for LowerBounding<
-        rustsat_cadical::CaDiCaL<'term, 'learn>,
-        DbGte,
-        DbTotalizer,
-        ProofW,
-        OInit,
-        BCG,
-    >
+impl<'term, 'learn, PBE, CE, ProofW, OInit, BCG> super::InitCert
+    for LowerBounding<rustsat_cadical::CaDiCaL<'term, 'learn>, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)> + CertEncoding,
+    CE: card::BoundUpperIncremental + FromIterator<Lit> + CertEncoding,
     OInit: Initialize<rustsat_cadical::CaDiCaL<'term, 'learn>>,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
@@ -166,10 +160,12 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
+        ensure_proof_support::<PBE, CE>()?;
         let kernel = Kernel::new_cert(clauses, objs, var_manager, block_clause_gen, proof, opts)?;
         Self::init(kernel)
     }
@@ -253,9 +249,12 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    LowerBounding<rustsat_cadical::CaDiCaL<'learn, 'term>, DbGte, DbTotalizer, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
@@ -267,9 +266,8 @@ where
         if self.fence.data.is_empty() {
             for enc in self.obj_encs.iter_mut() {
                 if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
-                    let proof: *mut _ = self.kernel.oracle.proof_tracer_mut(pt_handle).proof_mut();
-                    let mut collector =
-                        CadicalCertCollector::new(&mut self.kernel.oracle, pt_handle);
+                    let proof: *mut _ = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
+                    let mut collector = CertCollector::new(&mut self.kernel.oracle, pt_handle);
                     enc.encode_ub_change_cert(
                         enc.offset()..enc.offset() + 1,
                         &mut collector,
@@ -288,58 +286,162 @@ where
             }
         }
         loop {
-            let assumps: Vec<_> = self.fence.assumps().collect();
-            let res = self.kernel.solve_assumps(&assumps)?;
-            match res {
-                SolverResult::Sat => self.kernel.harvest(
-                    &self.fence,
-                    &mut self.obj_encs,
-                    &[],
-                    &mut self.pareto_front,
-                )?,
-                SolverResult::Unsat => {
-                    let core = self.kernel.oracle.core()?;
-                    if core.is_empty() {
-                        self.kernel.log_routine_end()?;
-                        return Done(());
-                    }
-                    #[cfg(debug_assertions)]
-                    let old_fence = self.fence.bounds();
-                    self.kernel
-                        .update_fence(&mut self.fence, core, &mut self.obj_encs)?;
-                    #[cfg(debug_assertions)]
-                    {
-                        let new_fence = self.fence.bounds();
-                        let mut increased = false;
-                        for idx in 0..old_fence.len() {
-                            debug_assert!(old_fence[idx] <= new_fence[idx]);
-                            if old_fence[idx] < new_fence[idx] {
-                                increased = true;
-                            }
-                        }
-                        if !increased {
-                            panic!("fence has not increased");
-                        }
+            // Resume from the candidate a previous run was terminated at, or
+            // start from a known solution
+            let candidate = match self.kernel.interrupted_candidate.take() {
+                Some(cand) => Some(cand),
+                None => self.kernel.warm_start_candidate()?,
+            };
+            self.kernel.harvest(
+                &self.fence,
+                &mut self.obj_encs,
+                &[],
+                candidate,
+                &mut self.pareto_front,
+            )?;
+            if !self.kernel.warm_start.is_empty() {
+                // Use up the known solutions before increasing the fence
+                continue;
+            }
+            // Harvesting ends with an unsatisfiable call under the fence
+            let core = self.kernel.oracle.core()?;
+            if core.is_empty() {
+                self.kernel.log_routine_end()?;
+                return Done(());
+            }
+            // all non-dominated points within the fence have been found
+            self.kernel.record_exhausted_fence(self.fence.bounds());
+            #[cfg(debug_assertions)]
+            let old_fence = self.fence.bounds();
+            self.kernel
+                .update_fence(&mut self.fence, core, &mut self.obj_encs)?;
+            #[cfg(debug_assertions)]
+            {
+                let new_fence = self.fence.bounds();
+                let mut increased = false;
+                for idx in 0..old_fence.len() {
+                    debug_assert!(old_fence[idx] <= new_fence[idx]);
+                    if old_fence[idx] < new_fence[idx] {
+                        increased = true;
                     }
                 }
-                SolverResult::Interrupted => panic!("should have errored before"),
+                if !increased {
+                    panic!("fence has not increased");
+                }
             }
         }
     }
 }
 
-impl<'learn, 'term, PBE, CE, ProofW, OInit, BCG> CoreBoost
-    for LowerBounding<rustsat_cadical::CaDiCaL<'learn, 'term>, PBE, CE, ProofW, OInit, BCG>
+impl<O, PBE, CE, ProofW, OInit, BCG> Cooperate for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    fn share_front(&mut self, front: &SharedFront) -> anyhow::Result<()> {
+        self.kernel.connect_front(front)
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> Checkpoint for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    fn progress(&self) -> Progress {
+        self.kernel.progress(
+            &self.pareto_front,
+            AlgState::LowerBounding {
+                fence: self.fence.bounds(),
+                exhausted_fence: self.kernel.exhausted_fence.clone(),
+            },
+        )
+    }
+
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()> {
+        let AlgState::LowerBounding {
+            fence,
+            exhausted_fence,
+        } = progress.state
+        else {
+            anyhow::bail!("the progress was not taken of the lower-bounding algorithm");
+        };
+        anyhow::ensure!(
+            fence.is_empty() || fence.len() == self.obj_encs.len(),
+            "the progress does not match the number of objectives"
+        );
+        self.kernel.restore_front(
+            progress.pareto_front,
+            &progress.lower_bounds,
+            &mut self.obj_encs,
+            &mut self.pareto_front,
+        )?;
+        // Restore the fence, it is initialized when solving if empty
+        self.fence.data.clear();
+        for (enc, bound) in self.obj_encs.iter_mut().zip(fence) {
+            self.kernel.extend_encoding(enc, bound..bound + 1)?;
+            self.fence
+                .data
+                .push((bound, enc.enforce_ub(bound).unwrap()));
+        }
+        if let Some(bounds) = exhausted_fence {
+            self.kernel.record_exhausted_fence(bounds);
+        }
+        Ok(())
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> WarmStart for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    fn warm_start<I>(&mut self, solutions: I) -> anyhow::Result<Vec<Vec<isize>>>
+    where
+        I: IntoIterator<Item = Assignment>,
+    {
+        self.kernel.add_warm_start(solutions)
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> CoreBoost for LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
-    OInit: Initialize<rustsat_cadical::CaDiCaL<'learn, 'term>>,
+    OInit: Initialize<O>,
 {
-    fn core_boost(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool> {
         ensure!(
             self.kernel.stats.n_solve_calls == 0,
             "cannot perform core boosting after solve has been called"
         );
+        self.kernel.start_core_boosting(&limits);
+        let res = self.core_boost_main(opts);
+        self.kernel.detach_deadlines();
+        res
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> LowerBounding<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    /// Performs core boosting and merges the OLL reformulations into the
+    /// objective encodings
+    fn core_boost_main(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
         let Some(cb_res) = self.kernel.core_boost()? else {
             return Done(false);
         };
@@ -367,7 +469,7 @@ where
                 if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
                     if !reform.reformulations.is_empty() {
                         // delete remaining reformulation constraints from proof
-                        let proof = self.kernel.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                        let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
                         #[cfg(feature = "verbose-proofs")]
                         proof.comment(&format_args!(
                             "deleting remaining reformulation constraints from OLL of objective {oidx}"
@@ -409,17 +511,22 @@ impl Fence {
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'learn, 'term>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
 {
-    pub fn update_fence(
+    pub fn update_fence<PBE, CE>(
         &mut self,
         fence: &mut Fence,
         core: Vec<Lit>,
-        obj_encs: &mut [ObjEncoding<DbGte, DbTotalizer>],
-    ) -> MaybeTerminatedError {
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+    ) -> MaybeTerminatedError
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         let mut found = vec![false; fence.data.len()];
         'core: for clit in core {
             for (obj_idx, (bound, assumps)) in fence.data.iter_mut().enumerate() {
@@ -452,52 +559,71 @@ where
     }
 }
 
-impl<'term, 'learn, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'term, 'learn>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
 {
-    /// Runs the P-Minimal algorithm within the fence to harvest solutions
-    pub fn harvest<Col>(
+    /// Runs the P-Minimal algorithm within the fence to harvest solutions.
+    /// Returns once no further solution within the fence exists.
+    ///
+    /// `candidate`: optional candidate within the fence to start from instead
+    /// of solving
+    pub fn harvest<PBE, CE, Col>(
         &mut self,
         fence: &Fence,
-        obj_encs: &mut [ObjEncoding<DbGte, DbTotalizer>],
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
         base_assumps: &[Lit],
+        mut candidate: Option<(Vec<usize>, Assignment)>,
         collector: &mut Col,
     ) -> MaybeTerminatedError
     where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
         Col: Extend<NonDomPoint>,
     {
         debug_assert_eq!(obj_encs.len(), self.stats.n_objs);
         self.log_routine_start("harvest")?;
         let mut assumps = Vec::from(base_assumps);
         loop {
-            assumps.drain(base_assumps.len()..);
-            // Find minimization starting point
-            assumps.extend(fence.assumps());
-            let res = self.solve_assumps(&assumps)?;
-            if SolverResult::Unsat == res {
-                self.log_routine_end()?;
-                return Done(());
-            }
-            self.check_termination()?;
+            self.import_shared_front(obj_encs)?;
+            let (costs, solution) = if let Some(cand) = candidate.take() {
+                cand
+            } else {
+                assumps.drain(base_assumps.len()..);
+                // Find minimization starting point
+                assumps.extend(fence.assumps());
+                let res = self.solve_assumps(&assumps)?;
+                if SolverResult::Unsat == res {
+                    self.log_routine_end()?;
+                    return Done(());
+                }
+                self.check_termination()?;
+
+                let (costs, solution) = self.get_solution_and_internal_costs(
+                    self.opts
+                        .heuristic_improvements
+                        .solution_tightening
+                        .wanted(Phase::OuterLoop),
+                )?;
+                let cand_term = self.log_candidate(&costs, Phase::OuterLoop);
+                self.keep_candidate(cand_term, &costs, &solution)?;
+                let term: MaybeTerminatedError = self.check_termination().into();
+                self.keep_candidate(term, &costs, &solution)?;
+                (costs, solution)
+            };
 
             // Minimize solution
-            let (costs, solution) = self.get_solution_and_internal_costs(
-                self.opts
-                    .heuristic_improvements
-                    .solution_tightening
-                    .wanted(Phase::OuterLoop),
-            )?;
-            self.log_candidate(&costs, Phase::OuterLoop)?;
-            self.check_termination()?;
             self.phase_solution(solution.clone())?;
             let (costs, solution, block_switch) =
                 self.p_minimization(costs, solution, base_assumps, obj_encs)?;
 
             let assumps: Vec<_> = self.enforce_dominating(&costs, obj_encs)?.collect();
-            self.yield_solutions(costs.clone(), &assumps, solution.clone(), collector)?;
+            // With enumeration, the witness for the cut is the last enumerated solution
+            let (solution, yield_term) =
+                self.yield_solutions(costs.clone(), &assumps, solution, collector)?;
 
             // Block last Pareto point, if temporarily blocked
             if let Some((block_lit, ids)) = block_switch {
@@ -515,9 +641,9 @@ where
                         proof_stuff,
                         &mut self.oracle,
                     )?;
-                    let proof = self
-                        .oracle
-                        .proof_tracer_mut(&proof_stuff.pt_handle)
+                    let proof = proof_stuff
+                        .pt_handle
+                        .tracer_mut(&mut self.oracle)
                         .proof_mut();
                     let hints = [ConstraintId::last(2), ConstraintId::last(1), id.into()]
                         .into_iter()
@@ -531,15 +657,14 @@ where
                             [Derivation::Rup(clause![], hints.collect())],
                         )],
                     )?;
-                    cadical_veripb_tracer::CadicalCertCollector::new(
-                        &mut self.oracle,
-                        &proof_stuff.pt_handle,
-                    )
-                    .add_cert_clause(unit, unit_id)?;
+                    CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
+                        .add_cert_clause(unit, unit_id)?;
                 } else {
                     self.oracle.add_unit(block_lit)?;
                 }
             }
+            // The point is excluded, so a termination can be raised now
+            yield_term?;
         }
     }
 }
@@ -0,0 +1,114 @@
+//! # SAT Oracle Abstraction
+//!
+//! The algorithms are generic over the SAT oracle and only rely on the
+//! [RustSAT](https://github.com/chrjabs/rustsat) solver traits. Proof logging is
+//! only supported with CaDiCaL, the proof tracer connected to the oracle is
+//! therefore accessed through a [`TracerHandle`] that can only be created for
+//! CaDiCaL.
+
+use std::io;
+
+use cadical_veripb_tracer::{CadicalCertCollector, CadicalTracer};
+use pidgeons::AbsConstraintId;
+use rustsat::{encodings::CollectCertClauses, types::Clause, OutOfMemory};
+use rustsat_cadical::{CaDiCaL, ProofTracerHandle};
+
+/// Accesses the VeriPB proof tracer connected to an oracle of type `O`
+pub struct TracerHandle<O, ProofW: io::Write> {
+    /// The handle of the proof tracer
+    handle: ProofTracerHandle<CadicalTracer<ProofW>>,
+    /// Gets the proof tracer from the oracle
+    tracer_mut: for<'o> fn(
+        &'o mut O,
+        &ProofTracerHandle<CadicalTracer<ProofW>>,
+    ) -> &'o mut CadicalTracer<ProofW>,
+    /// Adds a clause that has already been derived in the proof to the oracle
+    add_cert_clause: fn(
+        &mut O,
+        &ProofTracerHandle<CadicalTracer<ProofW>>,
+        Clause,
+        AbsConstraintId,
+    ) -> Result<(), OutOfMemory>,
+}
+
+impl<'term, 'learn, ProofW> TracerHandle<CaDiCaL<'term, 'learn>, ProofW>
+where
+    ProofW: io::Write + 'static,
+{
+    pub fn new(handle: ProofTracerHandle<CadicalTracer<ProofW>>) -> Self {
+        TracerHandle {
+            handle,
+            tracer_mut: cadical_tracer_mut,
+            add_cert_clause: cadical_add_cert_clause,
+        }
+    }
+}
+
+fn cadical_tracer_mut<'o, ProofW>(
+    oracle: &'o mut CaDiCaL<'_, '_>,
+    pt_handle: &ProofTracerHandle<CadicalTracer<ProofW>>,
+) -> &'o mut CadicalTracer<ProofW>
+where
+    ProofW: io::Write + 'static,
+{
+    oracle.proof_tracer_mut(pt_handle)
+}
+
+fn cadical_add_cert_clause<ProofW>(
+    oracle: &mut CaDiCaL<'_, '_>,
+    pt_handle: &ProofTracerHandle<CadicalTracer<ProofW>>,
+    clause: Clause,
+    id: AbsConstraintId,
+) -> Result<(), OutOfMemory>
+where
+    ProofW: io::Write + 'static,
+{
+    CadicalCertCollector::new(oracle, pt_handle).add_cert_clause(clause, id)
+}
+
+impl<O, ProofW: io::Write> TracerHandle<O, ProofW> {
+    /// Gets the VeriPB proof tracer connected to the oracle
+    pub fn tracer_mut<'o>(&self, oracle: &'o mut O) -> &'o mut CadicalTracer<ProofW> {
+        (self.tracer_mut)(oracle, &self.handle)
+    }
+
+    /// Adds a clause that has already been derived in the proof with the
+    /// given ID to the oracle
+    pub fn add_cert_clause(
+        &self,
+        oracle: &mut O,
+        clause: Clause,
+        id: AbsConstraintId,
+    ) -> Result<(), OutOfMemory> {
+        (self.add_cert_clause)(oracle, &self.handle, clause, id)
+    }
+}
+
+/// Collects certified clauses from encodings into an oracle with a connected
+/// proof tracer
+pub(crate) struct CertCollector<'oracle, O, ProofW: io::Write> {
+    oracle: &'oracle mut O,
+    pt_handle: &'oracle TracerHandle<O, ProofW>,
+}
+
+impl<'oracle, O, ProofW: io::Write> CertCollector<'oracle, O, ProofW> {
+    pub fn new(oracle: &'oracle mut O, pt_handle: &'oracle TracerHandle<O, ProofW>) -> Self {
+        CertCollector { oracle, pt_handle }
+    }
+}
+
+impl<O, ProofW: io::Write> CollectCertClauses for CertCollector<'_, O, ProofW> {
+    fn extend_cert_clauses<T>(&mut self, cl_iter: T) -> Result<(), OutOfMemory>
+    where
+        T: IntoIterator<Item = (Clause, AbsConstraintId)>,
+    {
+        for (cl, id) in cl_iter {
+            self.add_cert_clause(cl, id)?;
+        }
+        Ok(())
+    }
+
+    fn add_cert_clause(&mut self, cl: Clause, id: AbsConstraintId) -> Result<(), OutOfMemory> {
+        self.pt_handle.add_cert_clause(self.oracle, cl, id)
+    }
+}
@@ -17,7 +17,6 @@
 //!     2009.
 use std::{fs, io};
 
-use cadical_veripb_tracer::CadicalCertCollector;
 use pidgeons::{AbsConstraintId, ConstraintId};
 use rustsat::{
     clause,
@@ -36,15 +35,20 @@ use rustsat::{
 use scuttle_proc::{oracle_bounds, KernelFunctions};
 
 use crate::{
+    checkpoint::{AlgState, Progress},
+    encodings::{ensure_proof_support, CertEncoding},
     options::{AfterCbOptions, CoreBoostingOptions, EnumOptions},
     termination::ensure,
-    types::{ParetoFront, VarManager},
+    types::{Constraint, NonDomPoint, ParetoFront, SharedFront, VarManager},
     EncodingStats, ExtendedSolveStats, KernelFunctions, KernelOptions, Limits,
-    MaybeTerminatedError::{self, Done},
+    MaybeTerminatedError::{self, Done, Error, Terminated},
     Phase,
 };
 
-use super::{coreboosting::MergeOllRef, proofs, CoreBoost, Kernel, ObjEncoding, Objective};
+use super::{
+    coreboosting::MergeOllRef, proofs, CertCollector, Checkpoint, Cooperate, CoreBoost, Kernel,
+    ObjEncoding, Objective, WarmStart,
+};
 
 /// The $P$-minimal algorithm type
 ///
@@ -75,15 +79,20 @@ pub struct PMinimal<
     pareto_front: ParetoFront,
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG> super::Solve
-    for PMinimal<rustsat_cadical::CaDiCaL<'term, 'learn>, DbGte, DbTotalizer, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> super::Solve for PMinimal<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    CE: card::BoundUpperIncremental + encodings::EncodeStats + CertEncoding,
+    O: SolveIncremental + SolveStats,
     BCG: Fn(Assignment) -> Clause,
     ProofW: io::Write + 'static,
 {
     fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
         self.kernel.start_solving(limits);
-        self.alg_main()
+        self.kernel.attach_deadlines();
+        let res = self.alg_main();
+        self.kernel.end_solving(res)
     }
 
     fn all_stats(
@@ -125,7 +134,8 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -137,8 +147,8 @@ where
 impl<'term, 'learn, PBE, CE, ProofW, OInit, BCG> super::InitCert
     for PMinimal<rustsat_cadical::CaDiCaL<'term, 'learn>, PBE, CE, ProofW, OInit, BCG>
 where
-    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)>,
-    CE: card::BoundUpperIncremental + FromIterator<Lit>,
+    PBE: pb::BoundUpperIncremental + FromIterator<(Lit, usize)> + CertEncoding,
+    CE: card::BoundUpperIncremental + FromIterator<Lit> + CertEncoding,
     OInit: Initialize<rustsat_cadical::CaDiCaL<'term, 'learn>>,
     ProofW: io::Write + 'static,
     BCG: Fn(Assignment) -> Clause,
@@ -156,10 +166,12 @@ where
         block_clause_gen: BCG,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
+        ensure_proof_support::<PBE, CE>()?;
         let kernel = Kernel::new_cert(clauses, objs, var_manager, block_clause_gen, proof, opts)?;
         Ok(Self::init(kernel))
     }
@@ -240,9 +252,12 @@ where
     }
 }
 
-impl<'learn, 'term, ProofW, OInit, BCG>
-    PMinimal<rustsat_cadical::CaDiCaL<'learn, 'term>, DbGte, DbTotalizer, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> PMinimal<O, PBE, CE, ProofW, OInit, BCG>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
     BCG: Fn(Assignment) -> Clause,
     ProofW: io::Write + 'static,
 {
@@ -251,24 +266,37 @@ where
         debug_assert_eq!(self.obj_encs.len(), self.kernel.stats.n_objs);
         self.kernel.log_routine_start("p-minimal")?;
         loop {
-            // Find minimization starting point
-            let res = self.kernel.solve()?;
-            if SolverResult::Unsat == res {
-                self.kernel.log_routine_end()?;
-                return Done(());
-            }
-            self.kernel.check_termination()?;
+            self.kernel.import_shared_front(&mut self.obj_encs)?;
+            let (costs, solution) = if let Some(cand) = self.kernel.interrupted_candidate.take() {
+                // Resume from the candidate a previous run was terminated at
+                cand
+            } else if let Some(cand) = self.kernel.warm_start_candidate()? {
+                // Start from a known solution
+                cand
+            } else {
+                // Find minimization starting point
+                let res = self.kernel.solve()?;
+                if SolverResult::Unsat == res {
+                    self.kernel.log_routine_end()?;
+                    return Done(());
+                }
+                self.kernel.check_termination()?;
+
+                let (costs, solution) = self.kernel.get_solution_and_internal_costs(
+                    self.kernel
+                        .opts
+                        .heuristic_improvements
+                        .solution_tightening
+                        .wanted(Phase::OuterLoop),
+                )?;
+                let cand_term = self.kernel.log_candidate(&costs, Phase::OuterLoop);
+                self.kernel.keep_candidate(cand_term, &costs, &solution)?;
+                let term: MaybeTerminatedError = self.kernel.check_termination().into();
+                self.kernel.keep_candidate(term, &costs, &solution)?;
+                (costs, solution)
+            };
 
             // Minimize solution
-            let (costs, solution) = self.kernel.get_solution_and_internal_costs(
-                self.kernel
-                    .opts
-                    .heuristic_improvements
-                    .solution_tightening
-                    .wanted(Phase::OuterLoop),
-            )?;
-            self.kernel.log_candidate(&costs, Phase::OuterLoop)?;
-            self.kernel.check_termination()?;
             self.kernel.phase_solution(solution.clone())?;
             let (costs, solution, block_switch) =
                 self.kernel
@@ -278,10 +306,11 @@ where
                 .kernel
                 .enforce_dominating(&costs, &mut self.obj_encs)?
                 .collect();
-            self.kernel.yield_solutions(
+            // With enumeration, the witness for the cut is the last enumerated solution
+            let (solution, yield_term) = self.kernel.yield_solutions(
                 costs.clone(),
                 &assumps,
-                solution.clone(),
+                solution,
                 &mut self.pareto_front,
             )?;
 
@@ -301,10 +330,9 @@ where
                         proof_stuff,
                         &mut self.kernel.oracle,
                     )?;
-                    let proof = self
-                        .kernel
-                        .oracle
-                        .proof_tracer_mut(&proof_stuff.pt_handle)
+                    let proof = proof_stuff
+                        .pt_handle
+                        .tracer_mut(&mut self.kernel.oracle)
                         .proof_mut();
                     let hints = [ConstraintId::last(2), ConstraintId::last(1), id.into()]
                         .into_iter()
@@ -318,31 +346,102 @@ where
                             [Derivation::Rup(clause![], hints.collect())],
                         )],
                     )?;
-                    cadical_veripb_tracer::CadicalCertCollector::new(
-                        &mut self.kernel.oracle,
-                        &proof_stuff.pt_handle,
-                    )
-                    .add_cert_clause(unit, unit_id)?;
+                    CertCollector::new(&mut self.kernel.oracle, &proof_stuff.pt_handle)
+                        .add_cert_clause(unit, unit_id)?;
                 } else {
                     self.kernel.oracle.add_unit(block_lit)?;
                 }
             }
+            // The point is excluded, so a termination can be raised now
+            yield_term?;
         }
     }
 }
 
-impl<'learn, 'term, PBE, CE, ProofW, OInit, BCG> CoreBoost
-    for PMinimal<rustsat_cadical::CaDiCaL<'learn, 'term>, PBE, CE, ProofW, OInit, BCG>
+impl<O, PBE, CE, ProofW, OInit, BCG> Cooperate for PMinimal<O, PBE, CE, ProofW, OInit, BCG>
+where
+    ProofW: io::Write,
+{
+    fn share_front(&mut self, front: &SharedFront) -> anyhow::Result<()> {
+        self.kernel.connect_front(front)
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> Checkpoint for PMinimal<O, PBE, CE, ProofW, OInit, BCG>
+where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    fn progress(&self) -> Progress {
+        self.kernel.progress(&self.pareto_front, AlgState::PMinimal)
+    }
+
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            progress.state == AlgState::PMinimal,
+            "the progress was not taken of the p-minimal algorithm"
+        );
+        self.kernel.restore_front(
+            progress.pareto_front,
+            &progress.lower_bounds,
+            &mut self.obj_encs,
+            &mut self.pareto_front,
+        )
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> WarmStart for PMinimal<O, PBE, CE, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+{
+    fn warm_start<I>(&mut self, solutions: I) -> anyhow::Result<Vec<Vec<isize>>>
+    where
+        I: IntoIterator<Item = Assignment>,
+    {
+        self.kernel.add_warm_start(solutions)
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> CoreBoost for PMinimal<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
     (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
-    OInit: Initialize<rustsat_cadical::CaDiCaL<'learn, 'term>>,
+    OInit: Initialize<O>,
 {
-    fn core_boost(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
+    fn core_boost_with_limits(
+        &mut self,
+        opts: CoreBoostingOptions,
+        limits: Limits,
+    ) -> MaybeTerminatedError<bool> {
         ensure!(
             self.kernel.stats.n_solve_calls == 0,
             "cannot perform core boosting after solve has been called"
         );
+        self.kernel.start_core_boosting(&limits);
+        let res = self.core_boost_main(opts);
+        self.kernel.detach_deadlines();
+        res
+    }
+}
+
+#[oracle_bounds]
+impl<O, PBE, CE, ProofW, OInit, BCG> PMinimal<O, PBE, CE, ProofW, OInit, BCG>
+where
+    O: SolveIncremental + SolveStats,
+    ProofW: io::Write + 'static,
+    (PBE, CE): MergeOllRef<PBE = PBE, CE = CE>,
+    OInit: Initialize<O>,
+{
+    /// Performs core boosting and merges the OLL reformulations into the
+    /// objective encodings
+    fn core_boost_main(&mut self, opts: CoreBoostingOptions) -> MaybeTerminatedError<bool> {
         let Some(cb_res) = self.kernel.core_boost()? else {
             return Done(false);
         };
@@ -370,7 +469,7 @@ where
                 if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.kernel.proof_stuff {
                     if !reform.reformulations.is_empty() {
                         // delete remaining reformulation constraints from proof
-                        let proof = self.kernel.oracle.proof_tracer_mut(pt_handle).proof_mut();
+                        let proof = pt_handle.tracer_mut(&mut self.kernel.oracle).proof_mut();
                         #[cfg(feature = "verbose-proofs")]
                         proof.comment(&format_args!(
                             "deleting remaining reformulation constraints from OLL of objective {oidx}"
@@ -394,26 +493,67 @@ where
     }
 }
 
-impl<'term, 'learn, ProofW, OInit, BCG>
-    Kernel<rustsat_cadical::CaDiCaL<'term, 'learn>, ProofW, OInit, BCG>
+#[oracle_bounds]
+impl<O, ProofW, OInit, BCG> Kernel<O, ProofW, OInit, BCG>
 where
+    O: SolveIncremental + SolveStats,
     ProofW: io::Write + 'static,
 {
-    /// Executes P-minimization from a cost and solution starting point
-    pub fn p_minimization(
+    /// Executes P-minimization from a cost and solution starting point. If
+    /// terminated, the last candidate is kept as the interrupted candidate.
+    #[allow(clippy::type_complexity)]
+    pub fn p_minimization<PBE, CE>(
         &mut self,
         mut costs: Vec<usize>,
         mut solution: Assignment,
         base_assumps: &[Lit],
-        obj_encs: &mut [ObjEncoding<DbGte, DbTotalizer>],
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
     ) -> MaybeTerminatedError<(
         Vec<usize>,
         Assignment,
         Option<(Lit, Option<(AbsConstraintId, Vec<AbsConstraintId>)>)>,
-    )> {
+    )>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         debug_assert_eq!(costs.len(), self.stats.n_objs);
         self.log_routine_start("p minimization")?;
-        let mut block_switch: Option<(Lit, Option<(AbsConstraintId, Vec<AbsConstraintId>)>)> = None;
+        let mut block_switch = None;
+        match self.p_minimization_loop(
+            &mut costs,
+            &mut solution,
+            &mut block_switch,
+            base_assumps,
+            obj_encs,
+        ) {
+            Done(_) => {
+                self.log_routine_end()?;
+                Done((costs, solution, block_switch))
+            }
+            Terminated(term) => {
+                self.interrupted_candidate = Some((costs, solution));
+                Terminated(term)
+            }
+            Error(err) => Error(err),
+        }
+    }
+
+    /// The minimization loop of [`Kernel::p_minimization`]. Returns once no
+    /// solution dominating the current candidate exists.
+    #[allow(clippy::type_complexity)]
+    fn p_minimization_loop<PBE, CE>(
+        &mut self,
+        costs: &mut Vec<usize>,
+        solution: &mut Assignment,
+        block_switch: &mut Option<(Lit, Option<(AbsConstraintId, Vec<AbsConstraintId>)>)>,
+        base_assumps: &[Lit],
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+    ) -> MaybeTerminatedError
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         let mut assumps = Vec::from(base_assumps);
         #[cfg(feature = "coarse-convergence")]
         let mut coarse = true;
@@ -434,7 +574,7 @@ where
             if self.opts.enumeration == EnumOptions::NoEnum {
                 // Block permanently since no enumeration at Pareto point
                 let (block_clause, reification_ids) =
-                    self.dominated_block_clause(&costs, obj_encs)?;
+                    self.dominated_block_clause(costs, obj_encs)?;
                 if let Some(proof_stuff) = &mut self.proof_stuff {
                     use rustsat::encodings::CollectCertClauses;
 
@@ -442,15 +582,15 @@ where
                     let cut_id = proofs::certify_pmin_cut(
                         obj_encs,
                         &self.objs,
-                        &costs,
-                        &solution,
+                        costs,
+                        solution,
                         self.var_manager.max_enc_var(),
                         proof_stuff,
                         &mut self.oracle,
                     )?;
-                    let proof = self
-                        .oracle
-                        .proof_tracer_mut(&proof_stuff.pt_handle)
+                    let proof = proof_stuff
+                        .pt_handle
+                        .tracer_mut(&mut self.oracle)
                         .proof_mut();
                     // since there might be reifications of multiple assumptions per one encoding
                     // involved, the actual clause might differ and is added as rup here
@@ -461,17 +601,15 @@ where
                             .chain([cut_id])
                             .map(ConstraintId::from),
                     )?;
-                    let mut collector = cadical_veripb_tracer::CadicalCertCollector::new(
-                        &mut self.oracle,
-                        &proof_stuff.pt_handle,
-                    );
+                    let mut collector =
+                        CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle);
                     collector.add_cert_clause(block_clause, clause_id)?;
                 } else {
                     self.oracle.add_clause(block_clause)?;
                 }
             } else {
                 // Permanently block last cadidate
-                if let Some((block_lit, ids)) = block_switch {
+                if let Some((block_lit, ids)) = block_switch.take() {
                     if let Some(proof_stuff) = &mut self.proof_stuff {
                         use pidgeons::{ConstraintId, Derivation, ProofGoal, ProofGoalId};
                         use rustsat::encodings::CollectCertClauses;
@@ -480,15 +618,15 @@ where
                         let id = proofs::certify_pmin_cut(
                             obj_encs,
                             &self.objs,
-                            &costs,
-                            &solution,
+                            costs,
+                            solution,
                             self.var_manager.max_enc_var(),
                             proof_stuff,
                             &mut self.oracle,
                         )?;
-                        let proof = self
-                            .oracle
-                            .proof_tracer_mut(&proof_stuff.pt_handle)
+                        let proof = proof_stuff
+                            .pt_handle
+                            .tracer_mut(&mut self.oracle)
                             .proof_mut();
                         let hints = [ConstraintId::last(2), ConstraintId::last(1), id.into()]
                             .into_iter()
@@ -502,24 +640,21 @@ where
                                 [Derivation::Rup(clause![], hints.collect())],
                             )],
                         )?;
-                        cadical_veripb_tracer::CadicalCertCollector::new(
-                            &mut self.oracle,
-                            &proof_stuff.pt_handle,
-                        )
-                        .add_cert_clause(unit, unit_id)?;
+                        CertCollector::new(&mut self.oracle, &proof_stuff.pt_handle)
+                            .add_cert_clause(unit, unit_id)?;
                     } else {
                         self.oracle.add_unit(block_lit)?;
                     }
                 }
                 // Temporarily block to allow for enumeration at Pareto point
-                let block_info = self.tmp_block_dominated(&costs, obj_encs)?;
+                let block_info = self.tmp_block_dominated(costs, obj_encs)?;
                 let blit = block_info.0;
-                block_switch = Some(block_info);
+                *block_switch = Some(block_info);
                 assumps.push(blit);
             }
             // Force next solution to dominate the current one
             #[cfg(not(feature = "coarse-convergence"))]
-            assumps.extend(self.enforce_dominating(&costs, obj_encs)?);
+            assumps.extend(self.enforce_dominating(costs, obj_encs)?);
             #[cfg(feature = "coarse-convergence")]
             assumps.extend(self.enforce_dominating_cert(&bound_costs, obj_encs)?);
 
@@ -527,24 +662,23 @@ where
             let res = self.solve_assumps(&assumps)?;
             if res == SolverResult::Unsat {
                 #[cfg(feature = "coarse-convergence")]
-                if bound_costs != costs {
+                if bound_costs != *costs {
                     // Switch to fine convergence
                     coarse = false;
                     continue;
                 }
-                self.log_routine_end()?;
-                // Termination criteria, return last solution and costs
-                return Done((costs, solution, block_switch));
+                // Termination criteria, the last candidate is P-minimal
+                return Done(());
             }
             self.check_termination()?;
 
-            (costs, solution) = self.get_solution_and_internal_costs(
+            (*costs, *solution) = self.get_solution_and_internal_costs(
                 self.opts
                     .heuristic_improvements
                     .solution_tightening
                     .wanted(Phase::Minimization),
             )?;
-            self.log_candidate(&costs, Phase::Minimization)?;
+            self.log_candidate(costs, Phase::Minimization)?;
             self.check_termination()?;
             self.phase_solution(solution.clone())?;
         }
@@ -552,14 +686,18 @@ where
 
     /// Gets assumptions to enforce that the next solution dominates the given
     /// cost point.
-    pub fn enforce_dominating<'a>(
+    pub fn enforce_dominating<'a, PBE, CE>(
         &'a mut self,
         costs: &'a [usize],
-        obj_encs: &'a mut [ObjEncoding<DbGte, DbTotalizer>],
-    ) -> anyhow::Result<impl Iterator<Item = Lit> + 'a> {
+        obj_encs: &'a mut [ObjEncoding<PBE, CE>],
+    ) -> anyhow::Result<impl Iterator<Item = Lit> + 'a>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         debug_assert_eq!(costs.len(), self.stats.n_objs);
         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-            let proof: *mut _ = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof: *mut _ = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
             #[cfg(feature = "verbose-proofs")]
             {
                 use itertools::Itertools;
@@ -569,7 +707,7 @@ where
                     costs.iter().format(", ")
                 ))?;
             }
-            let mut collector = CadicalCertCollector::new(&mut self.oracle, pt_handle);
+            let mut collector = CertCollector::new(&mut self.oracle, pt_handle);
             for (idx, &cst) in costs.iter().enumerate() {
                 let enc = &mut obj_encs[idx];
                 enc.encode_ub_change_cert(
@@ -593,11 +731,15 @@ where
 
     /// Gets a clause blocking solutions (weakly) dominated by the given cost point,
     /// given objective encodings.
-    pub fn dominated_block_clause(
+    pub fn dominated_block_clause<PBE, CE>(
         &mut self,
         costs: &[usize],
-        obj_encs: &mut [ObjEncoding<DbGte, DbTotalizer>],
-    ) -> anyhow::Result<(Clause, Vec<AbsConstraintId>)> {
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+    ) -> anyhow::Result<(Clause, Vec<AbsConstraintId>)>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         debug_assert_eq!(costs.len(), obj_encs.len());
         let mut reification_ids = Vec::new();
         let mut clause = Clause::default();
@@ -640,14 +782,96 @@ where
         Ok((clause, reification_ids))
     }
 
+    /// Imports the non-dominated points that other solvers published to the
+    /// shared Pareto front since the last import and permanently blocks the
+    /// solutions they weakly dominate
+    pub fn import_shared_front<PBE, CE>(
+        &mut self,
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+    ) -> anyhow::Result<()>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
+        let Some(shared_front) = &mut self.shared_front else {
+            return Ok(());
+        };
+        debug_assert!(self.proof_stuff.is_none());
+        for costs in shared_front.fetch() {
+            let internal = self.internalize_external_costs(&costs);
+            let (block_clause, _) = self.dominated_block_clause(&internal, obj_encs)?;
+            self.oracle.add_clause(block_clause)?;
+            self.approx.add_proven(costs);
+        }
+        Ok(())
+    }
+
+    /// Restores the non-dominated points and objective lower bounds of a
+    /// progress snapshot and permanently blocks the solutions weakly dominated
+    /// by the restored points
+    pub fn restore_front<PBE, CE, Col>(
+        &mut self,
+        pareto_front: ParetoFront<Vec<i32>>,
+        lower_bounds: &[usize],
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+        collector: &mut Col,
+    ) -> anyhow::Result<()>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+        Col: Extend<NonDomPoint>,
+    {
+        anyhow::ensure!(
+            self.stats.n_solve_calls == 0,
+            "progress can only be restored before solving"
+        );
+        anyhow::ensure!(
+            self.proof_stuff.is_none(),
+            "restoring progress is not supported with proof logging"
+        );
+        anyhow::ensure!(
+            lower_bounds.len() == self.stats.n_objs
+                && pareto_front
+                    .iter()
+                    .all(|pp| pp.costs().len() == self.stats.n_objs),
+            "the progress does not match the number of objectives"
+        );
+        for (obj, &lb) in self.objs.iter_mut().zip(lower_bounds) {
+            if lb > obj.lower_bound() {
+                obj.set_lower_bound(lb);
+            }
+        }
+        for point in pareto_front {
+            let costs = point.costs().clone();
+            let internal = self.internalize_external_costs(&costs);
+            let (block_clause, _) = self.dominated_block_clause(&internal, obj_encs)?;
+            self.oracle.add_clause(block_clause)?;
+            self.approx.add_proven(costs.clone());
+            let mut restored = NonDomPoint::new(costs);
+            for sol in point {
+                let mut assign = Assignment::default();
+                for lit in sol {
+                    assign.assign_lit(Lit::from_ipasir(lit)?);
+                }
+                restored.add_sol(assign);
+            }
+            collector.extend([restored]);
+        }
+        Ok(())
+    }
+
     /// Temporarily blocks solutions dominated by the given cost point. Returns
     /// and assumption that needs to be enforced in order for the blocking to be
     /// enforced.
-    pub fn tmp_block_dominated(
+    pub fn tmp_block_dominated<PBE, CE>(
         &mut self,
         costs: &[usize],
-        obj_encs: &mut [ObjEncoding<DbGte, DbTotalizer>],
-    ) -> anyhow::Result<(Lit, Option<(AbsConstraintId, Vec<AbsConstraintId>)>)> {
+        obj_encs: &mut [ObjEncoding<PBE, CE>],
+    ) -> anyhow::Result<(Lit, Option<(AbsConstraintId, Vec<AbsConstraintId>)>)>
+    where
+        PBE: pb::BoundUpperIncremental + CertEncoding,
+        CE: card::BoundUpperIncremental + CertEncoding,
+    {
         use pidgeons::VarLike;
 
         debug_assert_eq!(costs.len(), self.stats.n_objs);
@@ -656,7 +880,7 @@ where
         clause.add(block_lit);
         self.oracle.add_clause_ref(&clause).unwrap();
         if let Some(proofs::ProofStuff { pt_handle, .. }) = &self.proof_stuff {
-            let proof = self.oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof = pt_handle.tracer_mut(&mut self.oracle).proof_mut();
             let id = proof.redundant(&clause, [block_lit.var().substitute_fixed(true)], None)?;
             Ok((!block_lit, Some((id, reification_ids))))
         } else {
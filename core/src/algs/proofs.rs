@@ -12,24 +12,24 @@ use std::sync::Mutex;
 
 use cadical_veripb_tracer::{CadicalCertCollector, CadicalTracer};
 use pidgeons::{
-    AbsConstraintId, Axiom, ConstraintId, ConstraintLike, Derivation, OperationLike,
-    OperationSequence, Order, OrderVar, Proof, ProofGoal, ProofGoalId, ProofOnlyVar, Substitution,
-    VarLike,
+    AbsConstraintId, Axiom, Conclusion, ConstraintId, ConstraintLike, Derivation, OperationLike,
+    OperationSequence, Order, OrderVar, OutputGuarantee, Proof, ProofGoal, ProofGoalId,
+    ProofOnlyVar, Substitution, VarLike,
 };
 use rustsat::{
-    encodings::{atomics, card::DbTotalizer, pb::DbGte, CollectCertClauses},
-    instances::{Cnf, ManageVars},
+    encodings::{atomics, card, pb, CollectCertClauses},
+    instances::ManageVars,
     solvers::Initialize,
     types::{Assignment, Clause, Lit, RsHashMap, TernaryVal, Var, WLitIter},
 };
-use rustsat_cadical::CaDiCaL;
 
 use crate::{
-    types::{Instance, ObjEncoding, Objective, VarManager},
+    encodings::CertEncoding,
+    types::{Constraint, Instance, ObjEncoding, Objective, ParetoApproximation, VarManager},
     KernelOptions, Limits, Stats,
 };
 
-use super::default_blocking_clause;
+use super::{default_blocking_clause, Deadlines, TracerHandle};
 
 /// Trait for initializing algorithms
 pub trait InitCert: super::Init {
@@ -45,7 +45,8 @@ pub trait InitCert: super::Init {
         block_clause_gen: <Self as super::Init>::BlockClauseGen,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter;
 
@@ -56,7 +57,8 @@ pub trait InitCert: super::Init {
         proof: Proof<Self::ProofWriter>,
         block_clause_gen: <Self as super::Init>::BlockClauseGen,
     ) -> anyhow::Result<Self> {
-        Self::new_cert(inst.cnf, inst.objs, inst.vm, opts, proof, block_clause_gen)
+        let (constrs, objs, vm) = inst.into_parts();
+        Self::new_cert(constrs, objs, vm, opts, proof, block_clause_gen)
     }
 }
 
@@ -70,7 +72,8 @@ pub trait InitCertDefaultBlock: InitCert<BlockClauseGen = fn(Assignment) -> Clau
         proof: Proof<Self::ProofWriter>,
     ) -> anyhow::Result<Self>
     where
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
@@ -91,24 +94,41 @@ pub trait InitCertDefaultBlock: InitCert<BlockClauseGen = fn(Assignment) -> Clau
         opts: KernelOptions,
         proof: Proof<Self::ProofWriter>,
     ) -> anyhow::Result<Self> {
-        Self::new_cert(
-            inst.cnf,
-            inst.objs,
-            inst.vm,
-            opts,
-            proof,
-            default_blocking_clause,
-        )
+        let (constrs, objs, vm) = inst.into_parts();
+        Self::new_cert(constrs, objs, vm, opts, proof, default_blocking_clause)
     }
 }
 
 impl<Alg> InitCertDefaultBlock for Alg where Alg: InitCert<BlockClauseGen = fn(Assignment) -> Clause>
 {}
 
+/// Certified clauses collected before the proof is handed to the oracle
+#[derive(Default)]
+struct CertClauses(Vec<(Clause, AbsConstraintId)>);
+
+impl CollectCertClauses for CertClauses {
+    fn extend_cert_clauses<T>(&mut self, cl_iter: T) -> Result<(), rustsat::OutOfMemory>
+    where
+        T: IntoIterator<Item = (Clause, AbsConstraintId)>,
+    {
+        self.0.extend(cl_iter);
+        Ok(())
+    }
+
+    fn add_cert_clause(
+        &mut self,
+        cl: Clause,
+        id: AbsConstraintId,
+    ) -> Result<(), rustsat::OutOfMemory> {
+        self.0.push((cl, id));
+        Ok(())
+    }
+}
+
 /// Stuff to keep in the solver for proof logging
-pub struct ProofStuff<ProofW: io::Write> {
+pub struct ProofStuff<O, ProofW: io::Write> {
     /// The handle of the proof tracer
-    pub pt_handle: rustsat_cadical::ProofTracerHandle<CadicalTracer<ProofW>>,
+    pub pt_handle: TracerHandle<O, ProofW>,
     /// Mapping literal values to other literal values or other expressions
     pub value_map: Vec<(Axiom<AnyVar>, Value)>,
     /// Reified objective constraints
@@ -155,33 +175,34 @@ fn objectives_as_order(objs: &[Objective]) -> Order<Var, LbConstraint<OrderVar<V
     order
 }
 
-pub fn certify_pmin_cut<ProofW>(
-    obj_encs: &[ObjEncoding<
-        rustsat::encodings::pb::DbGte,
-        rustsat::encodings::card::DbTotalizer,
-    >],
+pub fn certify_pmin_cut<O, PBE, CE, ProofW>(
+    obj_encs: &[ObjEncoding<PBE, CE>],
     objs: &[Objective],
     costs: &[usize],
     witness: &Assignment,
     max_enc_var: Var,
-    proof_stuff: &mut ProofStuff<ProofW>,
-    oracle: &mut rustsat_cadical::CaDiCaL<'_, '_>,
+    proof_stuff: &mut ProofStuff<O, ProofW>,
+    oracle: &mut O,
 ) -> io::Result<AbsConstraintId>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
     ProofW: io::Write + 'static,
 {
     #[cfg(feature = "verbose-proofs")]
     {
         use itertools::Itertools;
-        oracle
-            .proof_tracer_mut(&proof_stuff.pt_handle)
+        proof_stuff
+            .pt_handle
+            .tracer_mut(oracle)
             .proof_mut()
             .comment(&format_args!(
                 "Introducing P-minimal cut for costs [{}] based on the following witness:",
                 costs.iter().format(", "),
             ))?;
-        oracle
-            .proof_tracer_mut(&proof_stuff.pt_handle)
+        proof_stuff
+            .pt_handle
+            .tracer_mut(oracle)
             .proof_mut()
             .comment(&format_args!("{witness}"))?;
     }
@@ -191,24 +212,24 @@ where
         .iter()
         .zip(objs)
         .zip(costs)
-        .map(|((enc, obj), cst)| {
+        .map(|((enc, obj), cst)| -> io::Result<_> {
             if *cst <= obj.lower_bound() {
                 debug_assert!(*cst == 0 || obj.reform_id().is_some());
-                return (None, obj.reform_id());
+                return Ok((None, obj.reform_id()));
             }
             if obj.n_lits() == 1 {
                 let lit = !obj.iter().next().unwrap().0;
-                return (Some(AnyVar::Solver(lit.var()).axiom(lit.is_neg())), None);
+                return Ok((Some(AnyVar::Solver(lit.var()).axiom(lit.is_neg())), None));
             }
             // weird edge case with a single oll totalizer output as the objective encoding
-            if enc.n_output_lits() == 1 {
+            if enc.n_output_lits()? == 1 {
                 let lit = enc.enforce_ub(enc.offset()).unwrap()[0];
-                return (Some(AnyVar::Solver(lit.var()).axiom(lit.is_neg())), None);
+                return Ok((Some(AnyVar::Solver(lit.var()).axiom(lit.is_neg())), None));
             }
             let (lit, def) = if enc.is_buffer_empty() {
                 // totalizer output semantics are identical with the required semantics, can
                 // therefore reuse totalizer output
-                let (olit, defs) = enc.output_proof_details(*cst);
+                let (olit, defs) = enc.output_proof_details(*cst)?;
                 (
                     AnyVar::Solver(olit.var()).axiom(olit.is_neg()),
                     defs.only_if_def,
@@ -216,14 +237,13 @@ where
             } else {
                 // totalizer output semantics do _not_ include the entire objective and can
                 // therefore not be used
-                let (lit, _, def) = get_obj_bound_constraint(*cst, obj, proof_stuff, oracle)
-                    .expect("failed to write proof");
+                let (lit, _, def) = get_obj_bound_constraint(*cst, obj, proof_stuff, oracle)?;
                 (lit, Some(def))
             };
             debug_assert!(def.is_some());
-            (Some(!lit), def)
+            Ok((Some(!lit), def))
         })
-        .collect();
+        .collect::<io::Result<_>>()?;
     let cut = LbConstraint::clause(cut_data.iter().filter_map(|&(l, _)| l));
 
     let ProofStuff {
@@ -231,21 +251,18 @@ where
         value_map,
         ..
     } = proof_stuff;
-    let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+    let proof = pt_handle.tracer_mut(oracle).proof_mut();
 
     // Extend witness to encoding variables under strict semantics
     // TODO: avoid clone
     let fixed_witness: Vec<Axiom<AnyVar>> = {
         // NOTE: assignments from `extend_assignment` have precendence, as they weill overwrite
         // assignments coming from the witness
-        let mut fixed_witness: Assignment = witness
-            .iter()
-            .chain(
-                obj_encs
-                    .iter()
-                    .flat_map(|enc| enc.extend_assignment(witness)),
-            )
-            .collect();
+        let mut enc_assignment = vec![];
+        for enc in obj_encs {
+            enc_assignment.extend(enc.extend_assignment(witness)?);
+        }
+        let mut fixed_witness: Assignment = witness.iter().chain(enc_assignment).collect();
         // NOTE: Need to do this in two steps since the identities depend on the encoding
         // assignments
         let mut solver_vars = Vec::new();
@@ -389,27 +406,93 @@ where
     Ok(cut_id)
 }
 
+/// Adds a clause that is only active while the given fresh literal is assumed
+///
+/// The clause is redundant since the witness can always set the literal to false. This is used
+/// to block enumerated solutions without excluding them, so that they remain available as
+/// witnesses for the P-minimal cut.
+pub fn add_tmp_clause<O, ProofW>(
+    mut clause: Clause,
+    tmp_lit: Lit,
+    proof_stuff: &ProofStuff<O, ProofW>,
+    oracle: &mut O,
+) -> anyhow::Result<()>
+where
+    ProofW: io::Write + 'static,
+{
+    clause.add(!tmp_lit);
+    let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
+    let id = proof.redundant(&clause, [Substitution::from(Axiom::from(!tmp_lit))], [])?;
+    proof_stuff.pt_handle.add_cert_clause(oracle, clause, id)?;
+    Ok(())
+}
+
+/// Permanently deactivates clauses added with [`add_tmp_clause`] by adding the negated
+/// temporary literals as units
+pub fn deactivate_tmp_lits<O, ProofW>(
+    tmp_lits: &[Lit],
+    proof_stuff: &ProofStuff<O, ProofW>,
+    oracle: &mut O,
+) -> anyhow::Result<()>
+where
+    ProofW: io::Write + 'static,
+{
+    for &lit in tmp_lits {
+        let unit = rustsat::clause![!lit];
+        let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
+        let id = proof.redundant(&unit, [Substitution::from(Axiom::from(!lit))], [])?;
+        proof_stuff.pt_handle.add_cert_clause(oracle, unit, id)?;
+    }
+    Ok(())
+}
+
+/// Logs an enumerated solution in the proof and excludes it
+///
+/// The solution must not be the witness of a later P-minimal cut.
+pub fn log_enumerated_solution<O, ProofW>(
+    solution: &Assignment,
+    max_enc_var: Var,
+    proof_stuff: &ProofStuff<O, ProofW>,
+    oracle: &mut O,
+) -> io::Result<()>
+where
+    ProofW: io::Write + 'static,
+{
+    let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
+    proof.exclude_solution(
+        solution
+            .clone()
+            .truncate(max_enc_var)
+            .iter()
+            .map(Axiom::from),
+    )?;
+    Ok(())
+}
+
 /// Certifies a reification of a cube of assumptions stemming from an encoding
 ///
 /// The certification will make the first assumption equal to the reification literal, while all
 /// others are implied by the reification literal
 ///
 /// Returns the ID stating that the first assumption implies the reification literal
-pub fn certify_assump_reification<ProofW>(
-    oracle: &mut CaDiCaL<'_, '_>,
-    proof_stuff: &mut ProofStuff<ProofW>,
+pub fn certify_assump_reification<O, PBE, CE, ProofW>(
+    oracle: &mut O,
+    proof_stuff: &mut ProofStuff<O, ProofW>,
     obj: &Objective,
-    enc: &ObjEncoding<DbGte, DbTotalizer>,
+    enc: &ObjEncoding<PBE, CE>,
     value: usize,
     reif_lit: Lit,
     assumps: &[Lit],
 ) -> anyhow::Result<AbsConstraintId>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
     ProofW: io::Write + 'static,
 {
     #[cfg(feature = "verbose-proofs")]
-    oracle
-        .proof_tracer_mut(&proof_stuff.pt_handle)
+    proof_stuff
+        .pt_handle
+        .tracer_mut(oracle)
         .proof_mut()
         .comment(&"reification of multiple assumptions for one objective encoding")?;
     Ok(if enc.is_buffer_empty() {
@@ -418,7 +501,7 @@ where
             value_map,
             ..
         } = proof_stuff;
-        let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+        let proof = pt_handle.tracer_mut(oracle).proof_mut();
 
         // NOTE: this assumes that the assumptions are outputs in increasing order
         let mut assumps = assumps.iter();
@@ -433,16 +516,16 @@ where
             None,
         )?;
         value_map.push((axiom(reif_lit), Value::Identical(a)));
-        CadicalCertCollector::new(oracle, pt_handle).add_cert_clause(clause, if_def)?;
-        let (first_olit, first_sems) = enc.output_proof_details(value);
+        pt_handle.add_cert_clause(oracle, clause, if_def)?;
+        let (first_olit, first_sems) = enc.output_proof_details(value)?;
         debug_assert_eq!(!first_olit, a);
         // all remaining assumptions are implied by the reification literal
         let mut val = value;
         for &a in assumps {
-            let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof = pt_handle.tracer_mut(oracle).proof_mut();
             val = enc.next_higher(val);
             // first convince veripb that `olit -> first_olit`
-            let (olit, sems) = enc.output_proof_details(val);
+            let (olit, sems) = enc.output_proof_details(val)?;
             debug_assert_eq!(!olit, a);
             let implication = proof.operations::<Var>(
                 &((OperationSequence::from(first_sems.if_def.unwrap())
@@ -457,10 +540,10 @@ where
             )?;
             let clause = atomics::lit_impl_lit(reif_lit, a);
             let id = proof.reverse_unit_prop(&clause, [implication.into(), if_def.into()])?;
-            CadicalCertCollector::new(oracle, pt_handle).add_cert_clause(clause, id)?;
+            pt_handle.add_cert_clause(oracle, clause, id)?;
             // delete implication
-            oracle
-                .proof_tracer_mut(pt_handle)
+            pt_handle
+                .tracer_mut(oracle)
                 .proof_mut()
                 .delete_ids::<AnyVar, LbConstraint<_>, _, _>(
                     [ConstraintId::from(implication)],
@@ -478,7 +561,7 @@ where
             value_map,
             ..
         } = proof_stuff;
-        let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+        let proof = pt_handle.tracer_mut(oracle).proof_mut();
 
         // the reification variable is implied by the objective bound
         let if_def = proof.redundant(
@@ -494,9 +577,9 @@ where
         value_map.push((axiom(!reif_lit), Value::ObjAtLeast(obj.idx(), value)));
         let mut val = value;
         for &a in assumps {
-            let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+            let proof = pt_handle.tracer_mut(oracle).proof_mut();
             let clause = atomics::lit_impl_lit(reif_lit, a);
-            let (olit, sems) = enc.output_proof_details(val);
+            let (olit, sems) = enc.output_proof_details(val)?;
             // NOTE: this assumes that objective encoding variables are higher than GTE variables
             // and that the buffered input variables are first in the assumptions
             let id = if a.var() < olit.var() {
@@ -522,34 +605,38 @@ where
                 val = enc.next_higher(val);
                 id
             };
-            CadicalCertCollector::new(oracle, pt_handle).add_cert_clause(clause, id)?;
+            pt_handle.add_cert_clause(oracle, clause, id)?;
         }
         only_if_def
     })
 }
 
-pub fn linsu_certify_lower_bound<ProofW>(
+pub fn linsu_certify_lower_bound<O, PBE, CE, ProofW>(
     base_assumps: &[Lit],
     cost: usize,
     core: &[Lit],
     obj: &Objective,
-    encoding: &ObjEncoding<DbGte, DbTotalizer>,
-    proof_stuff: &mut ProofStuff<ProofW>,
-    oracle: &mut rustsat_cadical::CaDiCaL<'_, '_>,
+    encoding: &ObjEncoding<PBE, CE>,
+    proof_stuff: &mut ProofStuff<O, ProofW>,
+    oracle: &mut O,
 ) -> io::Result<AbsConstraintId>
 where
+    PBE: pb::BoundUpperIncremental + CertEncoding,
+    CE: card::BoundUpperIncremental + CertEncoding,
     ProofW: io::Write + 'static,
 {
     // derive lower bound on objective in proof
-    let core_id = oracle
-        .proof_tracer_mut(&proof_stuff.pt_handle)
+    let core_id = proof_stuff
+        .pt_handle
+        .tracer_mut(oracle)
         .core_id()
         .expect("expected core id in proof");
     #[cfg(feature = "verbose-proofs")]
     {
         use itertools::Itertools;
-        oracle
-            .proof_tracer_mut(&proof_stuff.pt_handle)
+        proof_stuff
+            .pt_handle
+            .tracer_mut(oracle)
             .proof_mut()
             .comment(&format_args!(
                 "certifying linsu lower bound for bound {cost} from core [{}]",
@@ -565,13 +652,13 @@ where
     let core_id = if encoding.is_buffer_empty() {
         // encoding has empty buffer, output semantics can therefore be reused for objective bound
         // semantics
-        let (first_olit, first_sems) = encoding.output_proof_details(cost);
+        let (first_olit, first_sems) = encoding.output_proof_details(cost)?;
         if core.len() == 1 {
             // unit core explicitly implies bound
             debug_assert_eq!(core[0], first_olit);
             core_id
         } else {
-            let proof = oracle.proof_tracer_mut(&proof_stuff.pt_handle).proof_mut();
+            let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
 
             // convince veripb that `core_lit -> first_olit` and therefore
             // rewrite core as `first_olit` unit
@@ -586,10 +673,10 @@ where
             let mut implications = Vec::with_capacity(core.len());
             let mut val = cost;
             for &clit in &core[start..] {
-                let (mut olit, mut sems) = encoding.output_proof_details(val);
+                let (mut olit, mut sems) = encoding.output_proof_details(val)?;
                 while clit != olit {
                     val = encoding.next_higher(val);
-                    (olit, sems) = encoding.output_proof_details(val);
+                    (olit, sems) = encoding.output_proof_details(val)?;
                 }
                 let implication = proof.operations::<Var>(
                     &((OperationSequence::from(first_sems.if_def.unwrap())
@@ -635,19 +722,19 @@ where
         // introduce new proof variable for needed semantics
         let (ideal_lit, def_1, _) = get_obj_bound_constraint(cost, obj, proof_stuff, oracle)?;
 
-        let proof = oracle.proof_tracer_mut(&proof_stuff.pt_handle).proof_mut();
+        let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
 
         let mut implications = Vec::with_capacity(core.len());
         let mut val = cost;
         for &clit in &core[start..] {
             let clause = LbConstraint::clause([axiom(!clit), ideal_lit]);
-            let (mut olit, mut sems) = encoding.output_proof_details(val);
+            let (mut olit, mut sems) = encoding.output_proof_details(val)?;
             let implication = if clit.var() < olit.var() {
                 proof.reverse_unit_prop(&clause, [ConstraintId::from(def_1)])?
             } else {
                 while clit.var() != olit.var() {
                     val = encoding.next_higher(val);
-                    (olit, sems) = encoding.output_proof_details(val);
+                    (olit, sems) = encoding.output_proof_details(val)?;
                 }
                 let tmp = proof.operations::<AnyVar>(
                     &(OperationSequence::from(def_1) + sems.only_if_def.unwrap()),
@@ -684,11 +771,52 @@ where
     Ok(core_id)
 }
 
-pub fn get_obj_bound_constraint<ProofW>(
+/// Records the result of a solving run in the proof
+///
+/// If all non-dominated points were found, the proof is concluded with the contradiction that the
+/// tracer reports for the final oracle call. Otherwise no contradiction has been derived and the
+/// proof is concluded without claim.
+///
+/// VeriPB conclusions can neither state a set of non-dominated points with witnesses nor a lower
+/// bound set, and no output formula is produced, so the conclusion is always one of these two and
+/// the output guarantee is always `NONE`. Neither is configurable. The non-dominated points, and the
+/// feasible and lower bound points of a partial front, are only written as comments, which a
+/// checker does not verify.
+pub fn conclude_run<O, ProofW>(
+    approx: &ParetoApproximation,
+    proof_stuff: &ProofStuff<O, ProofW>,
+    oracle: &mut O,
+) -> io::Result<()>
+where
+    ProofW: io::Write + 'static,
+{
+    let proof = proof_stuff.pt_handle.tracer_mut(oracle).proof_mut();
+    for costs in approx.proven() {
+        proof.comment(&format_args!("non-dominated point: {costs:?}"))?;
+    }
+    if approx.is_complete() {
+        proof.comment(&"all non-dominated points found")?;
+        proof.update_default_conclusion::<Var>(
+            OutputGuarantee::None,
+            &Conclusion::Unsat(Some(ConstraintId::last(1))),
+        );
+        return Ok(());
+    }
+    for costs in approx.feasible() {
+        proof.comment(&format_args!("feasible point: {costs:?}"))?;
+    }
+    for costs in approx.lower_bounds() {
+        proof.comment(&format_args!("lower bound point: {costs:?}"))?;
+    }
+    proof.update_default_conclusion::<Var>(OutputGuarantee::None, &Conclusion::None);
+    Ok(())
+}
+
+pub fn get_obj_bound_constraint<O, ProofW>(
     value: usize,
     obj: &Objective,
-    proof_stuff: &mut ProofStuff<ProofW>,
-    oracle: &mut rustsat_cadical::CaDiCaL<'_, '_>,
+    proof_stuff: &mut ProofStuff<O, ProofW>,
+    oracle: &mut O,
 ) -> io::Result<(Axiom<AnyVar>, AbsConstraintId, AbsConstraintId)>
 where
     ProofW: io::Write + 'static,
@@ -698,7 +826,7 @@ where
         value_map,
         obj_bound_constrs,
     } = proof_stuff;
-    let proof = oracle.proof_tracer_mut(pt_handle).proof_mut();
+    let proof = pt_handle.tracer_mut(oracle).proof_mut();
 
     if let Some((lit, def_1, def_2)) = obj_bound_constrs.get(&(obj.idx(), value)) {
         return Ok((*lit, *def_1, *def_2));
@@ -827,11 +955,12 @@ where
     ) -> anyhow::Result<Self>
     where
         ProofW: io::Write + 'static,
-        Cls: IntoIterator<Item = Clause>,
+        Cls: IntoIterator,
+        Cls::Item: Into<Constraint>,
         Objs: IntoIterator<Item = (Obj, isize)>,
         Obj: WLitIter,
     {
-        use rustsat::{encodings::CollectCertClauses, solvers::Solve};
+        use rustsat::solvers::Solve;
 
         let mut stats = Stats {
             n_objs: 0,
@@ -839,25 +968,47 @@ where
             n_orig_clauses: 0,
             ..Default::default()
         };
+        let mut var_manager = var_manager;
+        let mut proof = proof;
+
+        // Encode native constraints with certified encodings, the constraint
+        // IDs are the positions in the VeriPB input
+        let mut cert_clauses = CertClauses::default();
+        for (idx, constr) in clauses.into_iter().enumerate() {
+            stats.n_orig_clauses += 1;
+            let id = AbsConstraintId::new(idx + 1);
+            let constr: Constraint = constr.into();
+            match constr {
+                Constraint::Clause(cl) => cert_clauses.add_cert_clause(cl, id)?,
+                Constraint::Card(constr) => card::cert::default_encode_cardinality_constraint(
+                    (constr, id),
+                    &mut cert_clauses,
+                    &mut var_manager,
+                    &mut proof,
+                )?,
+                Constraint::Pb(constr) => pb::cert::default_encode_pb_constraint(
+                    (constr, id),
+                    &mut cert_clauses,
+                    &mut var_manager,
+                    &mut proof,
+                )?,
+            }
+        }
+        // constraint encodings are part of the instance encoding
+        var_manager.mark_max_enc_var();
+
         let mut oracle = OInit::init();
         let pt_handle = oracle.connect_proof_tracer(CadicalTracer::new(proof), true);
         oracle.reserve(var_manager.max_var().unwrap())?;
-        let clauses: Cnf = clauses.into_iter().collect();
         let orig_cnf = if opts.store_cnf {
-            Some(clauses.clone())
+            Some(cert_clauses.0.iter().map(|(cl, _)| cl.clone()).collect())
         } else {
             None
         };
-        stats.n_orig_clauses = clauses.len();
 
         // Add clauses to solver
         let mut collector = CadicalCertCollector::new(&mut oracle, &pt_handle);
-        collector.extend_cert_clauses(
-            clauses
-                .into_iter()
-                .enumerate()
-                .map(|(idx, cl)| (cl, AbsConstraintId::new(idx + 1))),
-        )?;
+        collector.extend_cert_clauses(cert_clauses.0)?;
 
         let objs: Vec<_> = objs
             .into_iter()
@@ -941,13 +1092,22 @@ where
             opts,
             stats,
             lims: Limits::none(),
+            deadlines: Deadlines::default(),
             inpro: None,
+            approx: ParetoApproximation::default(),
+            exhausted_fence: None,
+            interrupted_candidate: None,
+            warm_start: vec![],
+            shared_front: None,
             logger: None,
+            sol_callback: None,
             term_flag: Arc::new(AtomicBool::new(false)),
             #[cfg(feature = "interrupt-oracle")]
             oracle_interrupter: Arc::new(Mutex::new(Box::new(interrupter))),
+            #[cfg(feature = "interrupt-oracle")]
+            watchdog: None,
             proof_stuff: Some(ProofStuff {
-                pt_handle,
+                pt_handle: TracerHandle::new(pt_handle),
                 value_map: Vec::default(),
                 obj_bound_constrs: RsHashMap::default(),
             }),
@@ -0,0 +1,717 @@
+//! # Solver Builder
+//!
+//! Configures an algorithm at runtime instead of through the generic
+//! parameters of the algorithm types. The built solver is returned as an
+//! object-safe [`SolverHandle`], so that the configuration can be chosen from
+//! user input without monomorphizing the calling code for every combination of
+//! algorithm, encodings and oracle.
+
+use std::{fmt, io};
+
+#[cfg(any(feature = "minisat", feature = "glucose"))]
+use rustsat::solvers::DefaultInitializer;
+use rustsat::{
+    encodings::{card, pb},
+    solvers::{Initialize, SolverStats},
+    types::Assignment,
+};
+use rustsat_cadical::CaDiCaL;
+
+use crate::{
+    algs::Interrupter,
+    checkpoint::Progress,
+    encodings::CardinalityNetwork,
+    logging::LoggerHandle,
+    options::{AfterCbOptions, DivConOptions, LexicographicOptions},
+    types::{Instance, ParetoApproximation, ParetoFront},
+    BiOptSat, Checkpoint, CoreBoost, CoreBoostingOptions, DivCon, EncodingStats,
+    InitCertDefaultBlock, InitDefaultBlock, KernelFunctions, KernelOptions, Lexicographic, Limits,
+    LowerBounding, MaybeTerminatedError, PMinimal, Solve, Stats, WarmStart, WriteSolverLog,
+};
+
+/// The CaDiCaL oracle, the only oracle supporting proof logging
+type Oracle = CaDiCaL<'static, 'static>;
+/// The MiniSat oracle
+#[cfg(feature = "minisat")]
+type Minisat = rustsat_minisat::core::Minisat;
+/// The Glucose oracle
+#[cfg(feature = "glucose")]
+type Glucose = rustsat_glucose::core::Glucose;
+
+/// The writer the proofs of built solvers are written to
+pub type ProofWriter = io::BufWriter<Box<dyn io::Write>>;
+
+/// The algorithm to build, together with its algorithm-specific options
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// The [`PMinimal`] algorithm
+    PMinimal,
+    /// The [`BiOptSat`] algorithm, only applicable to bi-objective instances
+    BiOptSat,
+    /// The [`LowerBounding`] algorithm
+    LowerBounding,
+    /// The [`DivCon`] algorithm
+    DivCon(DivConOptions),
+    /// The [`Lexicographic`] algorithm
+    Lexicographic(LexicographicOptions),
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Algorithm::PMinimal => write!(f, "p-minimal"),
+            Algorithm::BiOptSat => write!(f, "bioptsat"),
+            Algorithm::LowerBounding => write!(f, "lower-bounding"),
+            Algorithm::DivCon(_) => write!(f, "div-con"),
+            Algorithm::Lexicographic(_) => write!(f, "lexicographic"),
+        }
+    }
+}
+
+/// The encoding used for weighted objectives
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum PbEncoding {
+    /// Generalized totalizer encoding - Joshi et al. CP'15
+    #[default]
+    Gte,
+    /// Dynamic polynomial watchdog encoding - Paxian et al. SAT'18
+    Dpw,
+    /// Binary adder encoding - Eén and Sörensson JSAT'06
+    Adder,
+}
+
+impl fmt::Display for PbEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PbEncoding::Gte => write!(f, "gte"),
+            PbEncoding::Dpw => write!(f, "dpw"),
+            PbEncoding::Adder => write!(f, "adder"),
+        }
+    }
+}
+
+/// The encoding used for unweighted objectives
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum CardEncoding {
+    /// Totalizer encoding - Ballieux and Boufkhad CP'03
+    #[default]
+    Tot,
+    /// Cardinality network based on odd-even merge sort - Asín et al. Constraints'11
+    CardNet,
+}
+
+impl fmt::Display for CardEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardEncoding::Tot => write!(f, "tot"),
+            CardEncoding::CardNet => write!(f, "card-net"),
+        }
+    }
+}
+
+/// The SAT oracle of the built solver
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum OracleBackend {
+    /// CaDiCaL, the only oracle supporting proof logging
+    #[default]
+    Cadical,
+    /// MiniSat
+    #[cfg(feature = "minisat")]
+    Minisat,
+    /// Glucose
+    #[cfg(feature = "glucose")]
+    Glucose,
+}
+
+impl fmt::Display for OracleBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OracleBackend::Cadical => write!(f, "cadical"),
+            #[cfg(feature = "minisat")]
+            OracleBackend::Minisat => write!(f, "minisat"),
+            #[cfg(feature = "glucose")]
+            OracleBackend::Glucose => write!(f, "glucose"),
+        }
+    }
+}
+
+/// The profile of internal options that CaDiCaL is configured with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum CadicalConfig {
+    /// Set default advanced internal options
+    #[default]
+    Default,
+    /// Disable all internal preprocessing options
+    Plain,
+    /// Set internal options to target satisfiable instances
+    Sat,
+    /// Set internal options to target unsatisfiable instances
+    Unsat,
+}
+
+impl fmt::Display for CadicalConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CadicalConfig::Default => write!(f, "default"),
+            CadicalConfig::Plain => write!(f, "plain"),
+            CadicalConfig::Sat => write!(f, "sat"),
+            CadicalConfig::Unsat => write!(f, "unsat"),
+        }
+    }
+}
+
+impl From<CadicalConfig> for rustsat_cadical::Config {
+    fn from(cfg: CadicalConfig) -> Self {
+        match cfg {
+            CadicalConfig::Default => rustsat_cadical::Config::Default,
+            CadicalConfig::Plain => rustsat_cadical::Config::Plain,
+            CadicalConfig::Sat => rustsat_cadical::Config::Sat,
+            CadicalConfig::Unsat => rustsat_cadical::Config::Unsat,
+        }
+    }
+}
+
+/// Initializes CaDiCaL in its [`CadicalConfig::Default`] configuration
+pub struct CaDiCaLDefaultInit;
+
+impl Initialize<Oracle> for CaDiCaLDefaultInit {
+    fn init() -> Oracle {
+        let mut slv = CaDiCaL::default();
+        // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
+        // It might also be bad for core-guided search performance
+        slv.set_option("ilb", 0).unwrap();
+        slv
+    }
+}
+
+/// Initializes CaDiCaL in its [`CadicalConfig::Plain`] configuration
+pub struct CaDiCaLPlainInit;
+
+impl Initialize<Oracle> for CaDiCaLPlainInit {
+    fn init() -> Oracle {
+        init_cadical(CadicalConfig::Plain)
+    }
+}
+
+/// Initializes CaDiCaL in its [`CadicalConfig::Sat`] configuration
+pub struct CaDiCaLSatInit;
+
+impl Initialize<Oracle> for CaDiCaLSatInit {
+    fn init() -> Oracle {
+        init_cadical(CadicalConfig::Sat)
+    }
+}
+
+/// Initializes CaDiCaL in its [`CadicalConfig::Unsat`] configuration
+pub struct CaDiCaLUnsatInit;
+
+impl Initialize<Oracle> for CaDiCaLUnsatInit {
+    fn init() -> Oracle {
+        init_cadical(CadicalConfig::Unsat)
+    }
+}
+
+/// Initializes CaDiCaL with a non-default configuration
+fn init_cadical(config: CadicalConfig) -> Oracle {
+    let mut slv = CaDiCaL::default();
+    slv.set_configuration(config.into())
+        .expect("failed to set cadical config");
+    // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
+    // It might also be bad for core-guided search performance
+    slv.set_option("ilb", 0).unwrap();
+    slv
+}
+
+/// An object-safe handle to a solver built with a [`SolverBuilder`]
+pub trait SolverHandle {
+    /// Solves the instance under given limits. If not fully solved, returns an
+    /// early termination reason. Solving can be resumed as described for
+    /// [`Solve::solve`].
+    fn solve(&mut self, limits: Limits) -> MaybeTerminatedError;
+    /// Performs core boosting with the options passed to the builder under the
+    /// time limits of `limits`, as described for
+    /// [`CoreBoost::core_boost_with_limits`]. Returns false if the instance is
+    /// unsat. Does nothing if no core boosting options were set or if core
+    /// boosting was already performed.
+    fn core_boost(&mut self, limits: Limits) -> MaybeTerminatedError<bool>;
+    /// Gets the Pareto front discovered so far
+    fn pareto_front(&self) -> ParetoFront;
+    /// Gets an approximation of the Pareto front with a quality guarantee,
+    /// including feasible points not proven to be non-dominated yet
+    fn approximation(&self) -> ParetoApproximation;
+    /// Gets all statistics from the solver
+    fn all_stats(&self) -> (Stats, Option<SolverStats>, Option<Vec<EncodingStats>>);
+    /// Gets an interrupter to the solver
+    fn interrupter(&mut self) -> Interrupter;
+    /// Attaches a logger to the solver that receives all events
+    fn attach_logger(&mut self, logger: Box<dyn WriteSolverLog>) -> LoggerHandle;
+    /// Gets a snapshot of the current solving progress, see
+    /// [`Checkpoint::progress`]. Only supported by the [`Algorithm::PMinimal`],
+    /// [`Algorithm::BiOptSat`] and [`Algorithm::LowerBounding`] algorithms.
+    fn progress(&self) -> anyhow::Result<Progress>;
+    /// Restores the solving progress from a snapshot, see
+    /// [`Checkpoint::restore_progress`]. Only supported by the algorithms that
+    /// support [`SolverHandle::progress`].
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()>;
+    /// Seeds the search with known solutions, see [`WarmStart::warm_start`].
+    /// Only supported by the algorithms that support
+    /// [`SolverHandle::progress`].
+    fn warm_start(&mut self, solutions: Vec<Assignment>) -> anyhow::Result<Vec<Vec<isize>>>;
+}
+
+/// Dispatches over the objective encodings for algorithms that support all of
+/// them. `$init` is the method of [`InitArgs`] that initializes the algorithm.
+macro_rules! dispatch_encodings {
+    ($alg:ident, $oracle:ty, $oinit:ty, $init:ident, $pb_enc:expr, $card_enc:expr, $args:expr) => {
+        match ($pb_enc, $card_enc) {
+            (PbEncoding::Gte, CardEncoding::Tot) => {
+                $args.$init::<$alg<$oracle, pb::DbGte, card::DbTotalizer, ProofWriter, $oinit>, _>(
+                    AlgFns::seeded(core_boost),
+                    |_| (),
+                )
+            }
+            (PbEncoding::Gte, CardEncoding::CardNet) => {
+                $args.$init::<$alg<$oracle, pb::DbGte, CardinalityNetwork, ProofWriter, $oinit>, _>(
+                    AlgFns::seeded(no_core_boost),
+                    |_| (),
+                )
+            }
+            (PbEncoding::Dpw, CardEncoding::Tot) => $args.$init::<$alg<
+                $oracle,
+                pb::DynamicPolyWatchdog,
+                card::DbTotalizer,
+                ProofWriter,
+                $oinit,
+            >, _>(
+                AlgFns::seeded(no_core_boost), |_| ()
+            ),
+            (PbEncoding::Dpw, CardEncoding::CardNet) => $args.$init::<$alg<
+                $oracle,
+                pb::DynamicPolyWatchdog,
+                CardinalityNetwork,
+                ProofWriter,
+                $oinit,
+            >, _>(
+                AlgFns::seeded(no_core_boost),
+                |_| (),
+            ),
+            (PbEncoding::Adder, CardEncoding::Tot) => $args.$init::<$alg<
+                $oracle,
+                pb::BinaryAdder,
+                card::DbTotalizer,
+                ProofWriter,
+                $oinit,
+            >, _>(
+                AlgFns::seeded(no_core_boost), |_| ()
+            ),
+            (PbEncoding::Adder, CardEncoding::CardNet) => $args.$init::<$alg<
+                $oracle,
+                pb::BinaryAdder,
+                CardinalityNetwork,
+                ProofWriter,
+                $oinit,
+            >, _>(
+                AlgFns::seeded(no_core_boost),
+                |_| (),
+            ),
+        }
+    };
+}
+
+/// Builds the configured algorithm with the given oracle and oracle
+/// initializer. `$init` is the method of [`InitArgs`] that initializes the
+/// algorithm.
+macro_rules! build_alg {
+    ($builder:expr, $inst:expr, $oracle:ty, $oinit:ty, $init:ident) => {{
+        let SolverBuilder {
+            alg,
+            opts,
+            cb_opts,
+            pb_enc,
+            card_enc,
+            proof,
+            ..
+        } = $builder;
+        let args = InitArgs {
+            inst: $inst,
+            opts,
+            cb_opts,
+            proof,
+        };
+        match alg {
+            Algorithm::PMinimal => {
+                dispatch_encodings!(PMinimal, $oracle, $oinit, $init, pb_enc, card_enc, args)
+            }
+            Algorithm::BiOptSat => {
+                dispatch_encodings!(BiOptSat, $oracle, $oinit, $init, pb_enc, card_enc, args)
+            }
+            Algorithm::LowerBounding => {
+                dispatch_encodings!(
+                    LowerBounding,
+                    $oracle,
+                    $oinit,
+                    $init,
+                    pb_enc,
+                    card_enc,
+                    args
+                )
+            }
+            Algorithm::DivCon(dc_opts) => {
+                args.$init::<DivCon<$oracle, pb::DbGte, card::DbTotalizer, ProofWriter, $oinit>, _>(
+                    AlgFns::unseeded(core_boost),
+                    |alg| alg.set_options(dc_opts),
+                )
+            }
+            Algorithm::Lexicographic(lex_opts) => args.$init::<Lexicographic<
+                $oracle,
+                pb::DbGte,
+                card::DbTotalizer,
+                ProofWriter,
+                $oinit,
+            >, _>(
+                AlgFns::unseeded(core_boost),
+                |alg| alg.set_options(lex_opts),
+            ),
+        }
+    }};
+}
+
+/// Builds a solver from a configuration chosen at runtime
+///
+/// # Example
+///
+/// ```ignore
+/// let mut builder = SolverBuilder::new(Algorithm::PMinimal);
+/// builder.set_encodings(PbEncoding::Dpw, CardEncoding::CardNet);
+/// let mut solver = builder.build(inst)?;
+/// solver.solve(Limits::none())?;
+/// ```
+pub struct SolverBuilder {
+    /// The algorithm to build
+    alg: Algorithm,
+    /// The kernel options of the algorithm
+    opts: KernelOptions,
+    /// Options for core boosting, [`None`] to not core boost
+    cb_opts: Option<CoreBoostingOptions>,
+    /// The encoding for weighted objectives
+    pb_enc: PbEncoding,
+    /// The encoding for unweighted objectives
+    card_enc: CardEncoding,
+    /// The SAT oracle
+    oracle: OracleBackend,
+    /// The configuration of the CaDiCaL oracle
+    cadical_config: CadicalConfig,
+    /// The proof to log to, if any
+    proof: Option<pidgeons::Proof<ProofWriter>>,
+}
+
+impl SolverBuilder {
+    /// Creates a builder for the given algorithm with default options, without
+    /// core boosting and without proof logging
+    pub fn new(alg: Algorithm) -> Self {
+        SolverBuilder {
+            alg,
+            opts: KernelOptions::default(),
+            cb_opts: None,
+            pb_enc: PbEncoding::default(),
+            card_enc: CardEncoding::default(),
+            oracle: OracleBackend::default(),
+            cadical_config: CadicalConfig::default(),
+            proof: None,
+        }
+    }
+
+    /// Sets the kernel options of the algorithm
+    pub fn set_kernel_options(&mut self, opts: KernelOptions) -> &mut Self {
+        self.opts = opts;
+        self
+    }
+
+    /// Sets the options for core boosting, [`None`] to not core boost. Core
+    /// boosting is only implemented for the GTE and totalizer encodings.
+    pub fn set_core_boosting(&mut self, cb_opts: Option<CoreBoostingOptions>) -> &mut Self {
+        self.cb_opts = cb_opts;
+        self
+    }
+
+    /// Sets the objective encodings. The [`Algorithm::DivCon`] and
+    /// [`Algorithm::Lexicographic`] algorithms only support the default
+    /// encodings.
+    pub fn set_encodings(&mut self, pb_enc: PbEncoding, card_enc: CardEncoding) -> &mut Self {
+        self.pb_enc = pb_enc;
+        self.card_enc = card_enc;
+        self
+    }
+
+    /// Sets the SAT oracle. Proof logging is only supported with
+    /// [`OracleBackend::Cadical`].
+    pub fn set_oracle(&mut self, oracle: OracleBackend) -> &mut Self {
+        self.oracle = oracle;
+        self
+    }
+
+    /// Sets the configuration of the CaDiCaL oracle, only applicable with
+    /// [`OracleBackend::Cadical`]
+    pub fn set_cadical_config(&mut self, config: CadicalConfig) -> &mut Self {
+        self.cadical_config = config;
+        self
+    }
+
+    /// Sets the proof to log to, [`None`] to not log a proof. The proof must
+    /// have been initialized with the number of constraints of the instance
+    /// that is built. Proof logging is only implemented for the GTE and
+    /// totalizer encodings.
+    pub fn set_proof(&mut self, proof: Option<pidgeons::Proof<ProofWriter>>) -> &mut Self {
+        self.proof = proof;
+        self
+    }
+
+    /// Builds the solver for the given instance
+    ///
+    /// # Errors
+    ///
+    /// If the configuration is not supported on the instance, or if
+    /// initializing the algorithm fails.
+    pub fn build(self, inst: Instance) -> anyhow::Result<Box<dyn SolverHandle>> {
+        self.check(&inst)?;
+        match self.oracle {
+            OracleBackend::Cadical => match self.cadical_config {
+                CadicalConfig::Default => self.build_with::<CaDiCaLDefaultInit>(inst),
+                CadicalConfig::Plain => self.build_with::<CaDiCaLPlainInit>(inst),
+                CadicalConfig::Sat => self.build_with::<CaDiCaLSatInit>(inst),
+                CadicalConfig::Unsat => self.build_with::<CaDiCaLUnsatInit>(inst),
+            },
+            #[cfg(feature = "minisat")]
+            OracleBackend::Minisat => {
+                build_alg!(self, inst, Minisat, DefaultInitializer, init_uncert)
+            }
+            #[cfg(feature = "glucose")]
+            OracleBackend::Glucose => {
+                build_alg!(self, inst, Glucose, DefaultInitializer, init_uncert)
+            }
+        }
+    }
+
+    /// Checks that the configuration is supported on the instance
+    fn check(&self, inst: &Instance) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.alg != Algorithm::BiOptSat || inst.n_objs() == 2,
+            "the bioptsat algorithm can only be run on bi-objective problems"
+        );
+        if self.oracle != OracleBackend::Cadical {
+            anyhow::ensure!(
+                self.proof.is_none(),
+                "proof logging is only supported with the cadical oracle, not {}",
+                self.oracle
+            );
+            anyhow::ensure!(
+                self.cadical_config == CadicalConfig::Default,
+                "a cadical configuration can only be set with the cadical oracle"
+            );
+        }
+        if self.proof.is_some() {
+            if let Some(cb_opts) = &self.cb_opts {
+                anyhow::ensure!(
+                    !matches!(cb_opts.after, AfterCbOptions::Inpro(_)),
+                    "inprocessing is not supported with proof logging"
+                );
+            }
+        }
+        if self.pb_enc == PbEncoding::Gte && self.card_enc == CardEncoding::Tot {
+            return Ok(());
+        }
+        anyhow::ensure!(
+            !matches!(self.alg, Algorithm::DivCon(_) | Algorithm::Lexicographic(_)),
+            "the {} algorithm is only implemented for the GTE and totalizer encodings",
+            self.alg
+        );
+        anyhow::ensure!(
+            self.cb_opts.is_none(),
+            "core boosting is only implemented for the GTE and totalizer encodings"
+        );
+        anyhow::ensure!(
+            self.proof.is_none(),
+            "proof logging is only implemented for the GTE and totalizer encodings"
+        );
+        Ok(())
+    }
+
+    /// Builds the solver with CaDiCaL and the given oracle initializer
+    fn build_with<OInit>(self, inst: Instance) -> anyhow::Result<Box<dyn SolverHandle>>
+    where
+        OInit: Initialize<Oracle> + 'static,
+    {
+        build_alg!(self, inst, Oracle, OInit, init)
+    }
+}
+
+/// The arguments for initializing an algorithm
+struct InitArgs {
+    inst: Instance,
+    opts: KernelOptions,
+    cb_opts: Option<CoreBoostingOptions>,
+    proof: Option<pidgeons::Proof<ProofWriter>>,
+}
+
+impl InitArgs {
+    /// Initializes the algorithm and wraps it in a handle. The `setup` closure
+    /// is called on the algorithm after initialization.
+    fn init<Alg, Setup>(
+        self,
+        fns: AlgFns<Alg>,
+        setup: Setup,
+    ) -> anyhow::Result<Box<dyn SolverHandle>>
+    where
+        Alg: InitDefaultBlock + InitCertDefaultBlock<ProofWriter = ProofWriter> + Solve + 'static,
+        Setup: FnOnce(&mut Alg),
+    {
+        let mut alg = if let Some(proof) = self.proof {
+            Alg::from_instance_default_blocking_cert(self.inst, self.opts, proof)?
+        } else {
+            Alg::from_instance_default_blocking(self.inst, self.opts)?
+        };
+        setup(&mut alg);
+        Ok(Box::new(Handle {
+            alg,
+            cb_opts: self.cb_opts,
+            fns,
+        }))
+    }
+
+    /// Variant of [`InitArgs::init`] for oracles that do not support proof
+    /// logging
+    #[cfg(any(feature = "minisat", feature = "glucose"))]
+    fn init_uncert<Alg, Setup>(
+        self,
+        fns: AlgFns<Alg>,
+        setup: Setup,
+    ) -> anyhow::Result<Box<dyn SolverHandle>>
+    where
+        Alg: InitDefaultBlock + Solve + 'static,
+        Setup: FnOnce(&mut Alg),
+    {
+        debug_assert!(self.proof.is_none());
+        let mut alg = Alg::from_instance_default_blocking(self.inst, self.opts)?;
+        setup(&mut alg);
+        Ok(Box::new(Handle {
+            alg,
+            cb_opts: self.cb_opts,
+            fns,
+        }))
+    }
+}
+
+/// Performs core boosting on an algorithm
+type CoreBoostFn<Alg> = fn(&mut Alg, CoreBoostingOptions, Limits) -> MaybeTerminatedError<bool>;
+
+fn core_boost<Alg: CoreBoost>(
+    alg: &mut Alg,
+    opts: CoreBoostingOptions,
+    limits: Limits,
+) -> MaybeTerminatedError<bool> {
+    alg.core_boost_with_limits(opts, limits)
+}
+
+/// Replaces [`core_boost`] for configurations that do not support core boosting
+fn no_core_boost<Alg>(
+    _alg: &mut Alg,
+    _opts: CoreBoostingOptions,
+    _limits: Limits,
+) -> MaybeTerminatedError<bool> {
+    MaybeTerminatedError::Error(anyhow::anyhow!(
+        "core boosting is only implemented for the GTE and totalizer encodings"
+    ))
+}
+
+/// The functionality that not every built algorithm supports
+struct AlgFns<Alg> {
+    core_boost: CoreBoostFn<Alg>,
+    progress: fn(&Alg) -> anyhow::Result<Progress>,
+    restore_progress: fn(&mut Alg, Progress) -> anyhow::Result<()>,
+    warm_start: fn(&mut Alg, Vec<Assignment>) -> anyhow::Result<Vec<Vec<isize>>>,
+}
+
+impl<Alg> AlgFns<Alg> {
+    /// For algorithms that support checkpoints and warm starts
+    fn seeded(core_boost: CoreBoostFn<Alg>) -> Self
+    where
+        Alg: Checkpoint + WarmStart,
+    {
+        AlgFns {
+            core_boost,
+            progress: |alg| Ok(alg.progress()),
+            restore_progress: |alg, progress| alg.restore_progress(progress),
+            warm_start: |alg, solutions| alg.warm_start(solutions),
+        }
+    }
+
+    /// For algorithms that support neither checkpoints nor warm starts
+    fn unseeded(core_boost: CoreBoostFn<Alg>) -> Self {
+        AlgFns {
+            core_boost,
+            progress: |_| Err(unseeded("checkpoints")),
+            restore_progress: |_, _| Err(unseeded("checkpoints")),
+            warm_start: |_, _| Err(unseeded("warm starts")),
+        }
+    }
+}
+
+fn unseeded(what: &str) -> anyhow::Error {
+    anyhow::anyhow!("{what} are only supported for p-minimal, bioptsat and lower-bounding")
+}
+
+/// A built algorithm together with its core boosting configuration
+struct Handle<Alg> {
+    alg: Alg,
+    cb_opts: Option<CoreBoostingOptions>,
+    fns: AlgFns<Alg>,
+}
+
+impl<Alg: Solve> SolverHandle for Handle<Alg> {
+    fn solve(&mut self, limits: Limits) -> MaybeTerminatedError {
+        self.alg.solve(limits)
+    }
+
+    fn core_boost(&mut self, limits: Limits) -> MaybeTerminatedError<bool> {
+        match self.cb_opts.take() {
+            Some(opts) => (self.fns.core_boost)(&mut self.alg, opts, limits),
+            None => MaybeTerminatedError::Done(true),
+        }
+    }
+
+    fn pareto_front(&self) -> ParetoFront {
+        self.alg.pareto_front()
+    }
+
+    fn approximation(&self) -> ParetoApproximation {
+        self.alg.approximation()
+    }
+
+    fn all_stats(&self) -> (Stats, Option<SolverStats>, Option<Vec<EncodingStats>>) {
+        self.alg.all_stats()
+    }
+
+    fn interrupter(&mut self) -> Interrupter {
+        self.alg.interrupter()
+    }
+
+    fn attach_logger(&mut self, logger: Box<dyn WriteSolverLog>) -> LoggerHandle {
+        self.alg.attach_logger(logger)
+    }
+
+    fn progress(&self) -> anyhow::Result<Progress> {
+        (self.fns.progress)(&self.alg)
+    }
+
+    fn restore_progress(&mut self, progress: Progress) -> anyhow::Result<()> {
+        (self.fns.restore_progress)(&mut self.alg, progress)
+    }
+
+    fn warm_start(&mut self, solutions: Vec<Assignment>) -> anyhow::Result<Vec<Vec<isize>>> {
+        (self.fns.warm_start)(&mut self.alg, solutions)
+    }
+}
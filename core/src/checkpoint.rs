@@ -0,0 +1,98 @@
+//! # Checkpoints
+//!
+//! Snapshots of the solving progress of an algorithm that can be written to
+//! disk and restored in a new solver for the same instance, e.g., when the
+//! solving process gets pre-empted.
+
+#[cfg(feature = "serde")]
+use std::{
+    ffi::OsString,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+#[cfg(feature = "serde")]
+use anyhow::Context;
+
+use crate::types::ParetoFront;
+
+/// The solving progress of an algorithm
+///
+/// All bounds are internal costs, i.e., without the offsets and multipliers
+/// of the objectives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub struct Progress {
+    /// The non-dominated points found so far, with the solutions given as
+    /// IPASIR literals
+    pub pareto_front: ParetoFront<Vec<i32>>,
+    /// The lower bounds on the objectives, e.g., derived by core boosting
+    pub lower_bounds: Vec<usize>,
+    /// The algorithm-specific state
+    pub state: AlgState,
+}
+
+/// The algorithm-specific part of the solving progress
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum AlgState {
+    /// The $P$-minimal algorithm only needs the solutions dominated by the
+    /// found non-dominated points to be blocked
+    PMinimal,
+    /// The lower-bounding algorithm
+    LowerBounding {
+        /// The bounds of the current fence, empty if not yet initialized
+        fence: Vec<usize>,
+        /// The bounds of the last fence known to not contain any further
+        /// non-dominated points
+        exhausted_fence: Option<Vec<usize>>,
+    },
+    /// The BiOptSat algorithm
+    BiOptSat {
+        /// The value of the decreasing objective at the last non-dominated
+        /// point found. All further non-dominated points have a lower value.
+        dec_bound: Option<usize>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl Progress {
+    /// Writes the progress to a checkpoint file. The checkpoint is written to
+    /// a temporary file first and then moved to the given path, so that an
+    /// existing checkpoint is not corrupted if writing gets interrupted.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let mut tmp_path = OsString::from(path);
+        tmp_path.push(".tmp");
+        let mut writer = io::BufWriter::new(
+            fs::File::create(&tmp_path)
+                .with_context(|| format!("failed to create checkpoint file {tmp_path:?}"))?,
+        );
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("failed to move checkpoint file to {path:?}"))?;
+        Ok(())
+    }
+
+    /// Reads the progress from a checkpoint file
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let reader = io::BufReader::new(
+            fs::File::open(path)
+                .with_context(|| format!("failed to open checkpoint file {path:?}"))?,
+        );
+        serde_json::from_reader(reader)
+            .with_context(|| format!("failed to parse checkpoint file {path:?}"))
+    }
+}
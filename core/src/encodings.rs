@@ -0,0 +1,174 @@
+//! # Objective Encodings
+//!
+//! The algorithms are generic over the encodings used for weighted and
+//! unweighted objectives. Any incremental upper-bounding encoding from
+//! [RustSAT](https://github.com/chrjabs/rustsat) can be used by implementing
+//! [`CertEncoding`] for it. Proof logging and core boosting are only supported
+//! with [`pb::DbGte`] and [`card::DbTotalizer`].
+//!
+//! This module additionally provides a [`CardinalityNetwork`] for unweighted
+//! objectives.
+
+use std::{io, ops::Range};
+
+use rustsat::{
+    encodings::{card, pb, totdb, CollectCertClauses},
+    instances::ManageVars,
+    types::{Assignment, Lit},
+};
+
+mod cardnet;
+pub use cardnet::CardinalityNetwork;
+
+/// Encoding-specific functionality used when proof logging. All methods have
+/// default implementations for encodings without proof logging support, so
+/// that any RustSAT encoding can be used by implementing this trait without a
+/// body. Initializing an algorithm with a proof fails for such encodings.
+pub trait CertEncoding {
+    /// Whether the encoding supports proof logging
+    const SUPPORTS_PROOFS: bool = false;
+
+    /// Lazily extends the encoding for the given range of upper bounds while
+    /// certifying the added clauses in the proof
+    fn encode_ub_change_cert<Col, ProofW>(
+        &mut self,
+        _range: Range<usize>,
+        _collector: &mut Col,
+        _var_manager: &mut dyn ManageVars,
+        _proof: &mut pidgeons::Proof<ProofW>,
+    ) -> anyhow::Result<()>
+    where
+        Col: CollectCertClauses,
+        ProofW: io::Write,
+    {
+        Err(unsupported().into())
+    }
+
+    /// Gets the output literal for the given value together with its
+    /// semantic definitions in the proof
+    fn output_proof_details(&self, _value: usize) -> io::Result<(Lit, totdb::cert::SemDefs)> {
+        Err(unsupported())
+    }
+
+    /// Gets the assignment of the encoding variables under strict semantics
+    /// that extends the given assignment
+    fn extend_assignment(&self, _assign: &Assignment) -> io::Result<Vec<Lit>> {
+        Err(unsupported())
+    }
+
+    /// Checks whether the encoding includes input literals that are not yet
+    /// part of the encoded structure
+    fn is_buffer_empty(&self) -> bool {
+        true
+    }
+
+    /// Gets the number of output literals of the encoding
+    fn n_output_lits(&self) -> io::Result<usize> {
+        Err(unsupported())
+    }
+}
+
+/// The error returned by [`CertEncoding`] methods of encodings without proof
+/// logging support
+fn unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "proof logging is only supported with the GTE and totalizer encodings",
+    )
+}
+
+/// Checks that the given encodings support proof logging
+pub(crate) fn ensure_proof_support<PBE, CE>() -> anyhow::Result<()>
+where
+    PBE: CertEncoding,
+    CE: CertEncoding,
+{
+    if PBE::SUPPORTS_PROOFS && CE::SUPPORTS_PROOFS {
+        return Ok(());
+    }
+    Err(unsupported().into())
+}
+
+impl CertEncoding for pb::DbGte {
+    const SUPPORTS_PROOFS: bool = true;
+
+    fn encode_ub_change_cert<Col, ProofW>(
+        &mut self,
+        range: Range<usize>,
+        collector: &mut Col,
+        var_manager: &mut dyn ManageVars,
+        proof: &mut pidgeons::Proof<ProofW>,
+    ) -> anyhow::Result<()>
+    where
+        Col: CollectCertClauses,
+        ProofW: io::Write,
+    {
+        pb::cert::BoundUpperIncremental::encode_ub_change_cert(
+            self,
+            range,
+            collector,
+            var_manager,
+            proof,
+        )?;
+        Ok(())
+    }
+
+    fn output_proof_details(&self, value: usize) -> io::Result<(Lit, totdb::cert::SemDefs)> {
+        Ok(pb::DbGte::output_proof_details(self, value).unwrap())
+    }
+
+    fn extend_assignment(&self, assign: &Assignment) -> io::Result<Vec<Lit>> {
+        Ok(self.strictly_extend_assignment(assign).collect())
+    }
+
+    fn is_buffer_empty(&self) -> bool {
+        pb::DbGte::is_buffer_empty(self)
+    }
+
+    fn n_output_lits(&self) -> io::Result<usize> {
+        Ok(pb::DbGte::n_output_lits(self))
+    }
+}
+
+impl CertEncoding for card::DbTotalizer {
+    const SUPPORTS_PROOFS: bool = true;
+
+    fn encode_ub_change_cert<Col, ProofW>(
+        &mut self,
+        range: Range<usize>,
+        collector: &mut Col,
+        var_manager: &mut dyn ManageVars,
+        proof: &mut pidgeons::Proof<ProofW>,
+    ) -> anyhow::Result<()>
+    where
+        Col: CollectCertClauses,
+        ProofW: io::Write,
+    {
+        card::cert::BoundUpperIncremental::encode_ub_change_cert(
+            self,
+            range,
+            collector,
+            var_manager,
+            proof,
+        )?;
+        Ok(())
+    }
+
+    fn output_proof_details(&self, value: usize) -> io::Result<(Lit, totdb::cert::SemDefs)> {
+        Ok(card::DbTotalizer::output_proof_details(self, value).unwrap())
+    }
+
+    fn extend_assignment(&self, assign: &Assignment) -> io::Result<Vec<Lit>> {
+        Ok(self.strictly_extend_assignment(assign).collect())
+    }
+
+    fn n_output_lits(&self) -> io::Result<usize> {
+        Ok(card::DbTotalizer::n_output_lits(self))
+    }
+}
+
+impl CertEncoding for pb::DynamicPolyWatchdog {}
+
+impl CertEncoding for pb::BinaryAdder {}
+
+impl CertEncoding for CardinalityNetwork {}
@@ -0,0 +1,183 @@
+//! # Cardinality Network
+//!
+//! A sorting network based on Batcher's odd-even merge sort, encoded with only
+//! the clauses needed for upper bounds. Unlike the totalizer, the encoding is
+//! not built lazily for a range of bounds, but the entire network is encoded
+//! once the first bound is requested. This gives `O(n log^2 n)` clauses
+//! instead of `O(n^2)`.
+
+use std::ops::{Bound, RangeBounds};
+
+use rustsat::{
+    clause,
+    encodings::{card, CollectClauses, EncodeStats, Error},
+    instances::ManageVars,
+    types::Lit,
+};
+
+/// Comparator of two wires, stored as input literals and output literals
+#[derive(Debug, Clone, Copy)]
+struct Comparator {
+    inputs: [Lit; 2],
+    max: Lit,
+    min: Lit,
+}
+
+/// Cardinality network for upper bounding the number of true input literals
+#[derive(Debug, Default)]
+pub struct CardinalityNetwork {
+    /// The input literals to the network
+    in_lits: Vec<Lit>,
+    /// The comparators of the network, empty if not yet built
+    comparators: Vec<Comparator>,
+    /// The output literals of the network in descending order, empty if not
+    /// yet built
+    out_lits: Vec<Lit>,
+    /// The number of comparators that are already encoded
+    n_encoded: usize,
+    /// The number of clauses in the encoding
+    n_clauses: usize,
+    /// The number of variables in the encoding
+    n_vars: u32,
+}
+
+impl CardinalityNetwork {
+    /// Builds the comparator structure of the network, introducing all output
+    /// variables. Does nothing if the network is already built.
+    fn build(&mut self, var_manager: &mut dyn ManageVars) {
+        if !self.out_lits.is_empty() || self.in_lits.is_empty() {
+            return;
+        }
+        let n = self.in_lits.len();
+        let mut wires = self.in_lits.clone();
+        let mut p = 1;
+        while p < n {
+            let mut k = p;
+            while k >= 1 {
+                let mut j = k % p;
+                while j + k < n {
+                    for i in 0..std::cmp::min(k, n - j - k) {
+                        if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                            let (hi, lo) = (i + j, i + j + k);
+                            let comp = Comparator {
+                                inputs: [wires[hi], wires[lo]],
+                                max: var_manager.new_var().pos_lit(),
+                                min: var_manager.new_var().pos_lit(),
+                            };
+                            self.n_vars += 2;
+                            wires[hi] = comp.max;
+                            wires[lo] = comp.min;
+                            self.comparators.push(comp);
+                        }
+                    }
+                    j += 2 * k;
+                }
+                k /= 2;
+            }
+            p *= 2;
+        }
+        self.out_lits = wires;
+    }
+}
+
+impl FromIterator<Lit> for CardinalityNetwork {
+    fn from_iter<T: IntoIterator<Item = Lit>>(iter: T) -> Self {
+        CardinalityNetwork {
+            in_lits: iter.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl card::Encode for CardinalityNetwork {
+    fn n_lits(&self) -> usize {
+        self.in_lits.len()
+    }
+}
+
+impl card::EncodeIncremental for CardinalityNetwork {
+    fn reserve(&mut self, var_manager: &mut dyn ManageVars) {
+        self.build(var_manager);
+    }
+}
+
+impl card::BoundUpper for CardinalityNetwork {
+    fn encode_ub<Col, R>(
+        &mut self,
+        range: R,
+        collector: &mut Col,
+        var_manager: &mut dyn ManageVars,
+    ) -> Result<(), rustsat::OutOfMemory>
+    where
+        Col: CollectClauses,
+        R: RangeBounds<usize>,
+    {
+        self.n_encoded = 0;
+        card::BoundUpperIncremental::encode_ub_change(self, range, collector, var_manager)
+    }
+
+    fn enforce_ub(&self, ub: usize) -> Result<Vec<Lit>, Error> {
+        if ub >= self.in_lits.len() {
+            return Ok(vec![]);
+        }
+        if self.out_lits.is_empty() || self.n_encoded < self.comparators.len() {
+            return Err(Error::NotEncoded);
+        }
+        Ok(vec![!self.out_lits[ub]])
+    }
+}
+
+impl card::BoundUpperIncremental for CardinalityNetwork {
+    fn encode_ub_change<Col, R>(
+        &mut self,
+        range: R,
+        collector: &mut Col,
+        var_manager: &mut dyn ManageVars,
+    ) -> Result<(), rustsat::OutOfMemory>
+    where
+        Col: CollectClauses,
+        R: RangeBounds<usize>,
+    {
+        if !needs_bounds(&range, self.in_lits.len()) {
+            return Ok(());
+        }
+        self.build(var_manager);
+        collector.extend_clauses(self.comparators[self.n_encoded..].iter().flat_map(|comp| {
+            let [a, b] = comp.inputs;
+            [
+                clause![!a, comp.max],
+                clause![!b, comp.max],
+                clause![!a, !b, comp.min],
+            ]
+        }))?;
+        self.n_clauses += 3 * (self.comparators.len() - self.n_encoded);
+        self.n_encoded = self.comparators.len();
+        Ok(())
+    }
+}
+
+impl EncodeStats for CardinalityNetwork {
+    fn n_clauses(&self) -> usize {
+        self.n_clauses
+    }
+
+    fn n_vars(&self) -> u32 {
+        self.n_vars
+    }
+}
+
+/// Checks whether a range of upper bounds contains any bound that is not
+/// trivially satisfied with the given number of input literals
+fn needs_bounds<R: RangeBounds<usize>>(range: &R, n_lits: usize) -> bool {
+    let start = match range.start_bound() {
+        Bound::Included(&b) => b,
+        Bound::Excluded(&b) => b + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&b) => b + 1,
+        Bound::Excluded(&b) => b,
+        Bound::Unbounded => usize::MAX,
+    };
+    start < end && start < n_lits
+}
@@ -13,15 +13,29 @@ pub use options::{CoreBoostingOptions, KernelOptions, Limits};
 pub mod types;
 use types::NonDomPoint;
 
+pub mod encodings;
+
 pub mod prepro;
 
+pub mod portfolio;
+
+pub mod builder;
+pub use builder::{SolverBuilder, SolverHandle};
+
+pub mod checkpoint;
+
+pub mod logging;
+
 pub mod algs;
 pub use algs::{
-    CoreBoost, Init, InitCert, InitCertDefaultBlock, InitDefaultBlock, KernelFunctions, Solve,
+    Checkpoint, Cooperate, CoreBoost, Init, InitCert, InitCertDefaultBlock, InitDefaultBlock,
+    KernelFunctions, SolutionAction, Solve, WarmStart,
 };
 
 // Reexport algorithms
 pub use algs::bioptsat::BiOptSat;
+pub use algs::divcon::DivCon;
+pub use algs::lexicographic::Lexicographic;
 pub use algs::lowerbounding::LowerBounding;
 pub use algs::pminimal::PMinimal;
 
@@ -40,6 +54,11 @@ pub trait ExtendedSolveStats {
 
 /// Algorithm phases that the solver can be in
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Phase {
     /// Outer loop
     OuterLoop,
@@ -64,6 +83,7 @@ impl fmt::Display for Phase {
 
 /// Statistics of the solver
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Stats {
     /// The number of calls to [`Solve::solve`]
     pub n_solve_calls: usize,
@@ -85,6 +105,7 @@ pub struct Stats {
 
 /// Statistics of a used cardinality or pseudo-boolean encodings
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EncodingStats {
     /// The number of clauses in the encoding
     pub n_clauses: usize,
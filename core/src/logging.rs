@@ -0,0 +1,308 @@
+//! # Solver Loggers
+//!
+//! Implementations of [`WriteSolverLog`] that are not tied to the command line
+//! interface, and the [`MultiLogger`] combinator that dispatches events to
+//! multiple loggers attached to the same solver.
+
+use rustsat::solvers::SolverResult;
+
+use crate::{types::NonDomPoint, Phase, WriteSolverLog};
+
+#[cfg(feature = "serde")]
+mod jsonl;
+#[cfg(feature = "serde")]
+pub use jsonl::JsonlLogger;
+
+/// Selection of the events that are dispatched to a logger
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Log candidates along the search trace
+    pub log_candidates: bool,
+    /// Log found solutions
+    pub log_solutions: bool,
+    /// Log non-dominated points
+    pub log_non_dom: bool,
+    /// Log SAT oracle calls
+    pub log_oracle_calls: bool,
+    /// Log heuristic objective improvements
+    #[cfg(feature = "sol-tightening")]
+    pub log_heuristic_obj_improvement: bool,
+    /// Log fence changes of the lower-bounding algorithm
+    pub log_fence: bool,
+    /// Log routine starts and ends till this depth
+    pub log_routines: usize,
+    /// Log ideal and nadir points
+    pub log_bound_points: bool,
+    /// Log extracted cores and core exhaustion
+    pub log_cores: bool,
+    /// Log inprocessing
+    pub log_inpro: bool,
+    /// Log free-form messages
+    pub log_messages: bool,
+}
+
+impl LogFilter {
+    /// A filter that lets all events pass
+    pub fn all() -> Self {
+        LogFilter {
+            log_candidates: true,
+            log_solutions: true,
+            log_non_dom: true,
+            log_oracle_calls: true,
+            #[cfg(feature = "sol-tightening")]
+            log_heuristic_obj_improvement: true,
+            log_fence: true,
+            log_routines: usize::MAX,
+            log_bound_points: true,
+            log_cores: true,
+            log_inpro: true,
+            log_messages: true,
+        }
+    }
+
+    /// A filter that blocks all events
+    pub fn none() -> Self {
+        LogFilter {
+            log_candidates: false,
+            log_solutions: false,
+            log_non_dom: false,
+            log_oracle_calls: false,
+            #[cfg(feature = "sol-tightening")]
+            log_heuristic_obj_improvement: false,
+            log_fence: false,
+            log_routines: 0,
+            log_bound_points: false,
+            log_cores: false,
+            log_inpro: false,
+            log_messages: false,
+        }
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// A handle to a logger attached to a [`MultiLogger`], used for detaching it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LoggerHandle(usize);
+
+/// A logger dispatching all events to multiple loggers, each with its own
+/// [`LogFilter`]
+#[derive(Default)]
+pub struct MultiLogger {
+    /// The attached loggers with their handle and filter
+    loggers: Vec<(LoggerHandle, LogFilter, Box<dyn WriteSolverLog>)>,
+    /// The handle to give to the next attached logger
+    next_handle: usize,
+    /// The current depth of nested routines
+    routine_depth: usize,
+}
+
+impl MultiLogger {
+    /// Attaches a logger that receives all events passing the filter
+    pub fn attach<L: WriteSolverLog + 'static>(
+        &mut self,
+        logger: L,
+        filter: LogFilter,
+    ) -> LoggerHandle {
+        let handle = LoggerHandle(self.next_handle);
+        self.next_handle += 1;
+        self.loggers.push((handle, filter, Box::new(logger)));
+        handle
+    }
+
+    /// Detaches the logger with the given handle. Returns [`None`] if no such
+    /// logger is attached.
+    pub fn detach(&mut self, handle: LoggerHandle) -> Option<Box<dyn WriteSolverLog>> {
+        let idx = self.loggers.iter().position(|(h, ..)| *h == handle)?;
+        Some(self.loggers.remove(idx).2)
+    }
+
+    /// Gets the number of attached loggers
+    pub fn len(&self) -> usize {
+        self.loggers.len()
+    }
+
+    /// Checks whether no logger is attached
+    pub fn is_empty(&self) -> bool {
+        self.loggers.is_empty()
+    }
+
+    /// Dispatches an event to all loggers whose filter lets it pass
+    fn dispatch<P, F>(&mut self, pass: P, mut log: F) -> anyhow::Result<()>
+    where
+        P: Fn(&LogFilter) -> bool,
+        F: FnMut(&mut dyn WriteSolverLog) -> anyhow::Result<()>,
+    {
+        for (_, filter, logger) in &mut self.loggers {
+            if pass(filter) {
+                log(logger.as_mut())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WriteSolverLog for MultiLogger {
+    fn log_candidate(&mut self, costs: &[usize], phase: Phase) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_candidates, |l| l.log_candidate(costs, phase))
+    }
+
+    fn log_oracle_call(&mut self, result: SolverResult) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_oracle_calls, |l| l.log_oracle_call(result))
+    }
+
+    fn log_solution(&mut self) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_solutions, |l| l.log_solution())
+    }
+
+    fn log_non_dominated(&mut self, pareto_point: &NonDomPoint) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_non_dom, |l| l.log_non_dominated(pareto_point))
+    }
+
+    #[cfg(feature = "sol-tightening")]
+    fn log_heuristic_obj_improvement(
+        &mut self,
+        obj_idx: usize,
+        apparent_cost: usize,
+        improved_cost: usize,
+    ) -> anyhow::Result<()> {
+        self.dispatch(
+            |f| f.log_heuristic_obj_improvement,
+            |l| l.log_heuristic_obj_improvement(obj_idx, apparent_cost, improved_cost),
+        )
+    }
+
+    fn log_fence(&mut self, fence: &[usize]) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_fence, |l| l.log_fence(fence))
+    }
+
+    fn log_routine_start(&mut self, desc: &'static str) -> anyhow::Result<()> {
+        self.routine_depth += 1;
+        let depth = self.routine_depth;
+        self.dispatch(|f| f.log_routines >= depth, |l| l.log_routine_start(desc))
+    }
+
+    fn log_routine_end(&mut self) -> anyhow::Result<()> {
+        let depth = self.routine_depth;
+        self.routine_depth = self.routine_depth.saturating_sub(1);
+        self.dispatch(|f| f.log_routines >= depth, |l| l.log_routine_end())
+    }
+
+    fn log_end_solve(&mut self) -> anyhow::Result<()> {
+        // every logger closes its own open routines
+        self.routine_depth = 0;
+        self.dispatch(|_| true, |l| l.log_end_solve())
+    }
+
+    fn log_ideal(&mut self, ideal: &[usize]) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_bound_points, |l| l.log_ideal(ideal))
+    }
+
+    fn log_nadir(&mut self, nadir: &[usize]) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_bound_points, |l| l.log_nadir(nadir))
+    }
+
+    fn log_core(&mut self, weight: usize, len: usize, red_len: usize) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_cores, |l| l.log_core(weight, len, red_len))
+    }
+
+    fn log_core_exhaustion(&mut self, exhausted: usize, weight: usize) -> anyhow::Result<()> {
+        self.dispatch(
+            |f| f.log_cores,
+            |l| l.log_core_exhaustion(exhausted, weight),
+        )
+    }
+
+    fn log_inprocessing(
+        &mut self,
+        cls_before_after: (usize, usize),
+        fixed_lits: usize,
+        obj_range_before_after: Vec<(usize, usize)>,
+    ) -> anyhow::Result<()> {
+        self.dispatch(
+            |f| f.log_inpro,
+            |l| l.log_inprocessing(cls_before_after, fixed_lits, obj_range_before_after.clone()),
+        )
+    }
+
+    fn log_message(&mut self, msg: &str) -> anyhow::Result<()> {
+        self.dispatch(|f| f.log_messages, |l| l.log_message(msg))
+    }
+}
+
+impl<L: WriteSolverLog + ?Sized> WriteSolverLog for Box<L> {
+    fn log_candidate(&mut self, costs: &[usize], phase: Phase) -> anyhow::Result<()> {
+        (**self).log_candidate(costs, phase)
+    }
+
+    fn log_oracle_call(&mut self, result: SolverResult) -> anyhow::Result<()> {
+        (**self).log_oracle_call(result)
+    }
+
+    fn log_solution(&mut self) -> anyhow::Result<()> {
+        (**self).log_solution()
+    }
+
+    fn log_non_dominated(&mut self, pareto_point: &NonDomPoint) -> anyhow::Result<()> {
+        (**self).log_non_dominated(pareto_point)
+    }
+
+    #[cfg(feature = "sol-tightening")]
+    fn log_heuristic_obj_improvement(
+        &mut self,
+        obj_idx: usize,
+        apparent_cost: usize,
+        improved_cost: usize,
+    ) -> anyhow::Result<()> {
+        (**self).log_heuristic_obj_improvement(obj_idx, apparent_cost, improved_cost)
+    }
+
+    fn log_fence(&mut self, fence: &[usize]) -> anyhow::Result<()> {
+        (**self).log_fence(fence)
+    }
+
+    fn log_routine_start(&mut self, desc: &'static str) -> anyhow::Result<()> {
+        (**self).log_routine_start(desc)
+    }
+
+    fn log_routine_end(&mut self) -> anyhow::Result<()> {
+        (**self).log_routine_end()
+    }
+
+    fn log_end_solve(&mut self) -> anyhow::Result<()> {
+        (**self).log_end_solve()
+    }
+
+    fn log_ideal(&mut self, ideal: &[usize]) -> anyhow::Result<()> {
+        (**self).log_ideal(ideal)
+    }
+
+    fn log_nadir(&mut self, nadir: &[usize]) -> anyhow::Result<()> {
+        (**self).log_nadir(nadir)
+    }
+
+    fn log_core(&mut self, weight: usize, len: usize, red_len: usize) -> anyhow::Result<()> {
+        (**self).log_core(weight, len, red_len)
+    }
+
+    fn log_core_exhaustion(&mut self, exhausted: usize, weight: usize) -> anyhow::Result<()> {
+        (**self).log_core_exhaustion(exhausted, weight)
+    }
+
+    fn log_inprocessing(
+        &mut self,
+        cls_before_after: (usize, usize),
+        fixed_lits: usize,
+        obj_range_before_after: Vec<(usize, usize)>,
+    ) -> anyhow::Result<()> {
+        (**self).log_inprocessing(cls_before_after, fixed_lits, obj_range_before_after)
+    }
+
+    fn log_message(&mut self, msg: &str) -> anyhow::Result<()> {
+        (**self).log_message(msg)
+    }
+}
@@ -0,0 +1,184 @@
+//! # JSON Lines Logger
+//!
+//! A logger writing a machine-readable trace of all solver events.
+
+use std::{fs, io, path::Path, time::Instant};
+
+use cpu_time::ProcessTime;
+use rustsat::solvers::SolverResult;
+use serde_json::{json, Value};
+
+use crate::{types::NonDomPoint, Phase, WriteSolverLog};
+
+/// A logger writing every event as one JSON object per line (JSON lines).
+///
+/// Each object has an `event` field with the event type, a `time` field with
+/// the wall-clock time in seconds since the logger was created, and a
+/// `cpu_time` field with the CPU time of the process in seconds. The remaining
+/// fields depend on the event type.
+pub struct JsonlLogger<W: io::Write> {
+    /// The writer to write the events to
+    writer: W,
+    /// The time the logger was created
+    start: Instant,
+    /// The currently running routines and their start time
+    routine_stack: Vec<(&'static str, Instant)>,
+}
+
+impl<W: io::Write> JsonlLogger<W> {
+    /// Creates a logger writing to the given writer
+    pub fn new(writer: W) -> Self {
+        JsonlLogger {
+            writer,
+            start: Instant::now(),
+            routine_stack: vec![],
+        }
+    }
+
+    /// Consumes the logger and returns the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes an event with its fields as one line
+    fn write_event(&mut self, event: &str, fields: Value) -> anyhow::Result<()> {
+        let mut line = json!({
+            "event": event,
+            "time": self.start.elapsed().as_secs_f64(),
+            "cpu_time": ProcessTime::now().as_duration().as_secs_f64(),
+        });
+        if let (Value::Object(line), Value::Object(fields)) = (&mut line, fields) {
+            line.extend(fields);
+        }
+        serde_json::to_writer(&mut self.writer, &line)?;
+        writeln!(self.writer)?;
+        Ok(())
+    }
+}
+
+impl JsonlLogger<io::BufWriter<fs::File>> {
+    /// Creates a logger writing to a newly created file at the given path
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(io::BufWriter::new(fs::File::create(path)?)))
+    }
+}
+
+impl<W: io::Write> WriteSolverLog for JsonlLogger<W> {
+    fn log_candidate(&mut self, costs: &[usize], phase: Phase) -> anyhow::Result<()> {
+        self.write_event("candidate", json!({ "costs": costs, "phase": phase }))
+    }
+
+    fn log_oracle_call(&mut self, result: SolverResult) -> anyhow::Result<()> {
+        let result = match result {
+            SolverResult::Sat => "sat",
+            SolverResult::Unsat => "unsat",
+            SolverResult::Interrupted => "interrupted",
+        };
+        self.write_event("oracle-call", json!({ "result": result }))
+    }
+
+    fn log_solution(&mut self) -> anyhow::Result<()> {
+        self.write_event("solution", json!({}))
+    }
+
+    fn log_non_dominated(&mut self, pareto_point: &NonDomPoint) -> anyhow::Result<()> {
+        self.write_event(
+            "non-dominated",
+            json!({ "costs": pareto_point.costs(), "n_sols": pareto_point.n_sols() }),
+        )
+    }
+
+    #[cfg(feature = "sol-tightening")]
+    fn log_heuristic_obj_improvement(
+        &mut self,
+        obj_idx: usize,
+        apparent_cost: usize,
+        improved_cost: usize,
+    ) -> anyhow::Result<()> {
+        self.write_event(
+            "heuristic-obj-improvement",
+            json!({
+                "obj_idx": obj_idx,
+                "apparent_cost": apparent_cost,
+                "improved_cost": improved_cost,
+            }),
+        )
+    }
+
+    fn log_fence(&mut self, fence: &[usize]) -> anyhow::Result<()> {
+        self.write_event("fence", json!({ "bounds": fence }))
+    }
+
+    fn log_routine_start(&mut self, desc: &'static str) -> anyhow::Result<()> {
+        self.routine_stack.push((desc, Instant::now()));
+        self.write_event(
+            "routine-start",
+            json!({ "routine": desc, "depth": self.routine_stack.len() }),
+        )
+    }
+
+    fn log_routine_end(&mut self) -> anyhow::Result<()> {
+        let (desc, start) = self.routine_stack.pop().expect("routine stack out of sync");
+        self.write_event(
+            "routine-end",
+            json!({
+                "routine": desc,
+                "depth": self.routine_stack.len() + 1,
+                "duration": start.elapsed().as_secs_f64(),
+            }),
+        )
+    }
+
+    fn log_end_solve(&mut self) -> anyhow::Result<()> {
+        while !self.routine_stack.is_empty() {
+            self.log_routine_end()?;
+        }
+        self.write_event("end-solve", json!({}))?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn log_ideal(&mut self, ideal: &[usize]) -> anyhow::Result<()> {
+        self.write_event("ideal", json!({ "point": ideal }))
+    }
+
+    fn log_nadir(&mut self, nadir: &[usize]) -> anyhow::Result<()> {
+        self.write_event("nadir", json!({ "point": nadir }))
+    }
+
+    fn log_core(&mut self, weight: usize, len: usize, red_len: usize) -> anyhow::Result<()> {
+        self.write_event(
+            "core",
+            json!({ "weight": weight, "len": len, "reduced_len": red_len }),
+        )
+    }
+
+    fn log_core_exhaustion(&mut self, exhausted: usize, weight: usize) -> anyhow::Result<()> {
+        self.write_event(
+            "core-exhaustion",
+            json!({ "exhausted": exhausted, "weight": weight }),
+        )
+    }
+
+    fn log_inprocessing(
+        &mut self,
+        cls_before_after: (usize, usize),
+        fixed_lits: usize,
+        obj_range_before_after: Vec<(usize, usize)>,
+    ) -> anyhow::Result<()> {
+        self.write_event(
+            "inprocessing",
+            json!({
+                "clauses_before": cls_before_after.0,
+                "clauses_after": cls_before_after.1,
+                "fixed_lits": fixed_lits,
+                "obj_ranges_before": obj_range_before_after.iter().map(|r| r.0).collect::<Vec<_>>(),
+                "obj_ranges_after": obj_range_before_after.iter().map(|r| r.1).collect::<Vec<_>>(),
+            }),
+        )
+    }
+
+    fn log_message(&mut self, msg: &str) -> anyhow::Result<()> {
+        self.write_event("message", json!({ "message": msg }))
+    }
+}
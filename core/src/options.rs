@@ -2,7 +2,7 @@
 //!
 //! This module contains all configuration options or the $P$-minimal solver.
 
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use crate::Phase;
 
@@ -162,6 +162,12 @@ pub struct Limits {
     pub candidates: Option<usize>,
     /// The maximum number of SAT oracle calls to make
     pub oracle_calls: Option<usize>,
+    /// The maximum wall-clock time to spend solving
+    pub time: Option<Duration>,
+    /// The maximum CPU time to spend solving. The CPU time is measured for the
+    /// whole process, including other threads, e.g., the other runs of a
+    /// [`crate::portfolio::Portfolio`].
+    pub cpu_time: Option<Duration>,
 }
 
 impl Limits {
@@ -172,10 +178,21 @@ impl Limits {
             sols: None,
             candidates: None,
             oracle_calls: None,
+            time: None,
+            cpu_time: None,
         }
     }
 }
 
+/// Options for the divide and conquer algorithm
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct DivConOptions {
+    /// The recursion anchor to use
+    pub anchor: DivConAnchor,
+    /// When to build the objective encodings
+    pub build_encodings: BuildEncodings,
+}
+
 /// Possible recursion anchors for the divide and conquer algorithm
 #[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
 pub enum DivConAnchor {
@@ -205,6 +222,37 @@ impl fmt::Display for DivConAnchor {
     }
 }
 
+impl std::str::FromStr for DivConAnchor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = match s.split_once('(') {
+            Some((name, arg)) => {
+                let Some(arg) = arg.strip_suffix(')') else {
+                    return Err(format!("missing closing parenthesis in anchor `{s}`"));
+                };
+                (name, Some(arg))
+            }
+            None => (s, None),
+        };
+        match (name, arg) {
+            ("lin-su", None) => Ok(DivConAnchor::LinSu),
+            ("bioptsat", None) => Ok(DivConAnchor::BiOptSat),
+            ("p-minimal", Some(size)) => Ok(DivConAnchor::PMinimal(size.parse()?)),
+            ("lower-bounding", Some(size)) => Ok(DivConAnchor::LowerBounding(size.parse()?)),
+            ("n-minus", Some(x)) => {
+                Ok(DivConAnchor::NMinus(x.parse().map_err(|_| {
+                    format!("invalid subproblem size difference `{x}`")
+                })?))
+            }
+            _ => Err(format!(
+                "invalid anchor `{s}`, expected one of `lin-su`, `bioptsat`, `p-minimal(<size>)`, \
+                `lower-bounding(<size>)` or `n-minus(<x>)`"
+            )),
+        }
+    }
+}
+
 /// Possible options for building objective encodings in divide and conquer
 #[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -245,6 +293,32 @@ impl SubProblemSize {
             SubProblemSize::Smaller(smaller) => prob_size - *smaller,
         }
     }
+
+    /// Calculates the absolute problem size given the original instance size.
+    /// Returns [`None`] if the subproblem would be smaller than empty.
+    pub fn checked_absolute(&self, prob_size: usize) -> Option<usize> {
+        match self {
+            SubProblemSize::Abs(abs) => Some(*abs),
+            SubProblemSize::Smaller(smaller) => prob_size.checked_sub(*smaller),
+        }
+    }
+}
+
+impl std::str::FromStr for SubProblemSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid subproblem size `{s}`, expected `+<size>` or `-<size>`");
+        if let Some(size) = s.strip_prefix('+') {
+            Ok(SubProblemSize::Abs(size.parse().map_err(|_| invalid())?))
+        } else if let Some(size) = s.strip_prefix('-') {
+            Ok(SubProblemSize::Smaller(
+                size.parse().map_err(|_| invalid())?,
+            ))
+        } else {
+            Err(invalid())
+        }
+    }
 }
 
 impl fmt::Display for SubProblemSize {
@@ -255,3 +329,23 @@ impl fmt::Display for SubProblemSize {
         }
     }
 }
+
+/// Options for the lexicographic algorithm
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct LexicographicOptions {
+    /// The objective indices in order of decreasing priority. Objectives that
+    /// are not listed are optimized afterwards in order of their index.
+    pub priority: Vec<usize>,
+    /// Optimize each objective with core-guided OLL search instead of linear
+    /// sat-unsat search
+    pub core_guided: bool,
+}
+
+impl LexicographicOptions {
+    /// Gets the full order in which the objectives are optimized
+    pub fn order(&self, n_objs: usize) -> Vec<usize> {
+        let mut order = self.priority.clone();
+        order.extend((0..n_objs).filter(|idx| !self.priority.contains(idx)));
+        order
+    }
+}
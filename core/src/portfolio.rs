@@ -0,0 +1,338 @@
+//! # Portfolio Solving
+//!
+//! Runs multiple algorithm configurations in parallel on separate threads,
+//! each with its own CaDiCaL oracle. As soon as one configuration has found
+//! the entire Pareto front, all other runs are interrupted. If no run finishes,
+//! the partial Pareto fronts of all runs are merged. Optionally, the runs can
+//! cooperate by sharing the non-dominated points they find while running.
+
+use std::{
+    fmt, fs, io,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+};
+
+use rustsat::{
+    encodings::{card::DbTotalizer, pb::DbGte},
+    solvers::{DefaultInitializer, Initialize},
+};
+use rustsat_cadical::CaDiCaL;
+
+use crate::{
+    algs::Interrupter,
+    types::{Instance, ParetoFront, SharedFront},
+    BiOptSat, Cooperate, CoreBoost, CoreBoostingOptions, InitDefaultBlock, KernelFunctions,
+    KernelOptions, Limits, LowerBounding, MaybeTerminatedError, PMinimal, Solve, Stats,
+};
+
+/// The oracle used by all portfolio runs
+type Oracle = CaDiCaL<'static, 'static>;
+/// P-Minimal instantiation used
+type PMin<OInit> = PMinimal<Oracle, DbGte, DbTotalizer, io::BufWriter<fs::File>, OInit>;
+/// BiOptSat instantiation used
+type Bos<OInit> = BiOptSat<Oracle, DbGte, DbTotalizer, io::BufWriter<fs::File>, OInit>;
+/// Lower-bounding instantiation used
+type Lb<OInit> = LowerBounding<Oracle, DbGte, DbTotalizer, io::BufWriter<fs::File>, OInit>;
+
+/// The algorithms that can be run in a portfolio
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum PortfolioAlg {
+    /// The [`PMinimal`] algorithm
+    PMinimal,
+    /// The [`BiOptSat`] algorithm, only applicable to bi-objective instances
+    #[cfg_attr(feature = "clap", value(name = "bioptsat"))]
+    BiOptSat,
+    /// The [`LowerBounding`] algorithm
+    LowerBounding,
+}
+
+impl fmt::Display for PortfolioAlg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortfolioAlg::PMinimal => write!(f, "p-minimal"),
+            PortfolioAlg::BiOptSat => write!(f, "bioptsat"),
+            PortfolioAlg::LowerBounding => write!(f, "lower-bounding"),
+        }
+    }
+}
+
+/// A configuration to run in a portfolio
+#[derive(Clone)]
+pub struct PortfolioConfig {
+    /// The algorithm to run
+    pub alg: PortfolioAlg,
+    /// The kernel options of the algorithm
+    pub opts: KernelOptions,
+    /// Options for core boosting, [`None`] to not core boost
+    pub cb: Option<CoreBoostingOptions>,
+}
+
+impl PortfolioConfig {
+    /// Creates a configuration with default options and without core boosting
+    pub fn new(alg: PortfolioAlg) -> Self {
+        PortfolioConfig {
+            alg,
+            opts: KernelOptions::default(),
+            cb: None,
+        }
+    }
+}
+
+/// The result of a portfolio run
+#[derive(Debug, Clone)]
+pub struct PortfolioResult {
+    /// The Pareto front. If a configuration finished, this is the entire
+    /// Pareto front found by that configuration, otherwise it is the merge of
+    /// the partial Pareto fronts of all runs.
+    pub pareto_front: ParetoFront,
+    /// The index of the configuration that finished first, [`None`] if all
+    /// runs were terminated early
+    pub winner: Option<usize>,
+    /// The statistics of each run, in order of the configurations. Runs that
+    /// failed have default statistics.
+    pub stats: Vec<Stats>,
+}
+
+/// An interrupter to the runs of a portfolio
+#[derive(Clone, Default)]
+pub struct PortfolioInterrupter {
+    /// Whether the portfolio was interrupted
+    interrupted: Arc<AtomicBool>,
+    /// The interrupters of all runs started so far
+    interrupters: Arc<Mutex<Vec<Interrupter>>>,
+}
+
+impl PortfolioInterrupter {
+    /// Interrupts all runs of the portfolio asynchronously
+    pub fn interrupt(&self) {
+        let mut interrupters = self.interrupters.lock().unwrap();
+        self.interrupted.store(true, Ordering::Relaxed);
+        for interrupter in interrupters.iter_mut() {
+            interrupter.interrupt();
+        }
+    }
+
+    /// Registers the interrupter of a new run. If the portfolio has already
+    /// been interrupted, the run is interrupted right away.
+    fn register(&self, mut interrupter: Interrupter) {
+        let mut interrupters = self.interrupters.lock().unwrap();
+        if self.interrupted.load(Ordering::Relaxed) {
+            interrupter.interrupt();
+        }
+        interrupters.push(interrupter);
+    }
+
+    /// Resets the interrupter for a new portfolio run
+    fn reset(&self) {
+        let mut interrupters = self.interrupters.lock().unwrap();
+        self.interrupted.store(false, Ordering::Relaxed);
+        interrupters.clear();
+    }
+}
+
+/// A portfolio of algorithm configurations that are run in parallel
+///
+/// # Generics
+///
+/// - `OInit`: the oracle initializer used for all runs
+pub struct Portfolio<OInit = DefaultInitializer> {
+    /// The configurations to run
+    configs: Vec<PortfolioConfig>,
+    /// The interrupter for all runs
+    interrupter: PortfolioInterrupter,
+    /// Whether the runs share the non-dominated points they find
+    cooperative: bool,
+    phantom: PhantomData<fn() -> OInit>,
+}
+
+impl<OInit> Portfolio<OInit>
+where
+    OInit: Initialize<Oracle>,
+{
+    /// Creates a new portfolio of the given configurations
+    pub fn new(configs: Vec<PortfolioConfig>) -> Self {
+        Portfolio {
+            configs,
+            interrupter: PortfolioInterrupter::default(),
+            cooperative: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets whether the runs cooperate by sharing the non-dominated points
+    /// they find through a [`SharedFront`]. Only [`PortfolioAlg::PMinimal`]
+    /// and [`PortfolioAlg::LowerBounding`] runs take part in the cooperation.
+    pub fn set_cooperative(&mut self, cooperative: bool) {
+        self.cooperative = cooperative;
+    }
+
+    /// Gets the configurations of the portfolio
+    pub fn configs(&self) -> &[PortfolioConfig] {
+        &self.configs
+    }
+
+    /// Gets an interrupter to all runs of the portfolio
+    pub fn interrupter(&self) -> PortfolioInterrupter {
+        self.interrupter.clone()
+    }
+
+    /// Runs all configurations in parallel on the instance under the given
+    /// limits, which apply to each run individually. The CPU time is measured
+    /// for the whole process however, so all runs draw from the same CPU time
+    /// limit. Returns an error only if all runs failed.
+    pub fn solve(&self, inst: Instance, limits: Limits) -> anyhow::Result<PortfolioResult> {
+        anyhow::ensure!(
+            !self.configs.is_empty(),
+            "portfolio requires at least one configuration"
+        );
+        anyhow::ensure!(
+            inst.n_objs() == 2
+                || self
+                    .configs
+                    .iter()
+                    .all(|config| config.alg != PortfolioAlg::BiOptSat),
+            "the bioptsat algorithm can only be run on bi-objective problems"
+        );
+        self.interrupter.reset();
+        let shared_front = self.cooperative.then(SharedFront::default);
+
+        let (tx, rx) = mpsc::channel();
+        thread::scope(|scope| {
+            for (idx, config) in self.configs.iter().enumerate() {
+                let tx = tx.clone();
+                let inst = inst.clone();
+                let interrupter = &self.interrupter;
+                let shared_front = &shared_front;
+                scope.spawn(move || {
+                    let front = shared_front.as_ref();
+                    let res = match config.alg {
+                        PortfolioAlg::PMinimal => {
+                            run::<PMin<OInit>, _>(inst, config, limits, interrupter, |alg| {
+                                cooperate(alg, front)
+                            })
+                        }
+                        PortfolioAlg::BiOptSat => {
+                            run::<Bos<OInit>, _>(inst, config, limits, interrupter, |_| Ok(()))
+                        }
+                        PortfolioAlg::LowerBounding => {
+                            run::<Lb<OInit>, _>(inst, config, limits, interrupter, |alg| {
+                                cooperate(alg, front)
+                            })
+                        }
+                    };
+                    // the receiver only hangs up after all runs are done
+                    let _ = tx.send((idx, res));
+                });
+            }
+            drop(tx);
+
+            let mut won = None;
+            let mut fronts = vec![];
+            let mut stats = vec![Stats::default(); self.configs.len()];
+            let mut first_err = None;
+            for (idx, res) in rx {
+                match res {
+                    Ok(run) => {
+                        stats[idx] = run.stats;
+                        if run.complete && won.is_none() {
+                            won = Some(fronts.len());
+                            self.interrupter.interrupt();
+                        }
+                        fronts.push((idx, run.pareto_front));
+                    }
+                    Err(err) => {
+                        first_err.get_or_insert(err);
+                    }
+                }
+            }
+
+            if let Some(won) = won {
+                let winner = fronts[won].0;
+                // when cooperating, the points of the front are spread over all runs
+                let pareto_front = if self.cooperative {
+                    ParetoFront::merge(fronts.into_iter().map(|(_, pf)| pf))
+                } else {
+                    fronts.swap_remove(won).1
+                };
+                return Ok(PortfolioResult {
+                    pareto_front,
+                    winner: Some(winner),
+                    stats,
+                });
+            }
+            if fronts.is_empty() {
+                return Err(first_err.expect("portfolio runs neither finished nor failed"));
+            }
+            Ok(PortfolioResult {
+                pareto_front: ParetoFront::merge(fronts.into_iter().map(|(_, pf)| pf)),
+                winner: None,
+                stats,
+            })
+        })
+    }
+}
+
+/// The outcome of a single run of a portfolio
+struct RunResult {
+    /// The Pareto front found by the run
+    pareto_front: ParetoFront,
+    /// Whether the Pareto front is complete
+    complete: bool,
+    /// The statistics of the run
+    stats: Stats,
+}
+
+/// Performs a single run of a portfolio. The `connect` closure is called on
+/// the algorithm before solving.
+fn run<Alg, Con>(
+    inst: Instance,
+    config: &PortfolioConfig,
+    limits: Limits,
+    interrupter: &PortfolioInterrupter,
+    connect: Con,
+) -> anyhow::Result<RunResult>
+where
+    Alg: InitDefaultBlock + Solve + CoreBoost,
+    Con: FnOnce(&mut Alg) -> anyhow::Result<()>,
+{
+    let mut alg = Alg::from_instance_default_blocking(inst, config.opts)?;
+    connect(&mut alg)?;
+    interrupter.register(alg.interrupter());
+    let cont = if let Some(opts) = &config.cb {
+        match alg.core_boost_with_limits(opts.clone(), limits) {
+            MaybeTerminatedError::Done(cont) => Some(cont),
+            MaybeTerminatedError::Terminated(_) => None,
+            MaybeTerminatedError::Error(err) => return Err(err),
+        }
+    } else {
+        Some(true)
+    };
+    let complete = match cont {
+        // core boosting found the instance to be unsatisfiable
+        Some(false) => true,
+        Some(true) => match alg.solve(limits) {
+            MaybeTerminatedError::Done(()) => true,
+            MaybeTerminatedError::Terminated(_) => false,
+            MaybeTerminatedError::Error(err) => return Err(err),
+        },
+        None => false,
+    };
+    Ok(RunResult {
+        pareto_front: alg.pareto_front(),
+        complete,
+        stats: alg.stats(),
+    })
+}
+
+/// Connects an algorithm to the shared Pareto front, if the portfolio is cooperative
+fn cooperate<Alg: Cooperate>(alg: &mut Alg, front: Option<&SharedFront>) -> anyhow::Result<()> {
+    if let Some(front) = front {
+        alg.share_front(front)?;
+    }
+    Ok(())
+}
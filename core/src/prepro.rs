@@ -1,15 +1,31 @@
 //! # Instance Processing Happening _Before_ It's Being Passed To The Actual Solver
 
-use std::{cmp, ffi::OsString, fmt, path::Path};
+use std::{
+    cmp,
+    ffi::OsString,
+    fmt,
+    io::{self, BufRead},
+    path::Path,
+};
 
+use anyhow::Context;
 use maxpre::{MaxPre, PreproClauses};
 use rustsat::{
-    encodings::CollectClauses,
-    instances::{fio, ManageVars, MultiOptInstance, Objective, ReindexVars},
-    types::{Clause, Lit, RsHashMap, Var},
+    encodings::{card, pb, CollectClauses},
+    instances::{fio, Cnf, ManageVars, MultiOptInstance, Objective, ReindexVars},
+    types::{
+        constraints::{CardConstraint, PbConstraint},
+        Clause, Lit, RsHashMap, Var, WLitIter,
+    },
 };
 
-use crate::types::{Instance, Parsed, Reindexer, VarManager};
+use crate::types::{Constraint, Instance, Parsed, Reindexer, VarManager};
+
+macro_rules! is_one_of {
+    ($a:expr, $($b:expr),*) => {
+        $( $a == $b || )* false
+    }
+}
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -25,6 +41,36 @@ pub enum FileFormat {
     Opb,
 }
 
+impl FileFormat {
+    /// Resolves the format of the given instance file, inferring it from the
+    /// file extension for [`FileFormat::Infer`]
+    pub fn resolve(self, inst_path: &Path) -> anyhow::Result<FileFormat> {
+        if self != FileFormat::Infer {
+            return Ok(self);
+        }
+        let Some(ext) = inst_path.extension() else {
+            anyhow::bail!(Error::NoFileExtension)
+        };
+        let path_without_compr = inst_path.with_extension("");
+        let ext = if is_one_of!(ext, "gz", "bz2", "xz") {
+            // Strip compression extension
+            match path_without_compr.extension() {
+                Some(ext) => ext,
+                None => anyhow::bail!(Error::NoFileExtension),
+            }
+        } else {
+            ext
+        };
+        if is_one_of!(ext, "mcnf", "bicnf", "wcnf", "cnf", "dimacs") {
+            Ok(FileFormat::Dimacs)
+        } else if is_one_of!(ext, "opb", "mopb", "pbmo") {
+            Ok(FileFormat::Opb)
+        } else {
+            anyhow::bail!(Error::UnknownFileExtension(OsString::from(ext)))
+        }
+    }
+}
+
 impl fmt::Display for FileFormat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -35,63 +81,357 @@ impl fmt::Display for FileFormat {
     }
 }
 
-macro_rules! is_one_of {
-    ($a:expr, $($b:expr),*) => {
-        $( $a == $b || )* false
-    }
-}
-
 #[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
 pub enum Error {
     #[error("Cannot infer file format from extension {0:?}")]
     UnknownFileExtension(OsString),
     #[error("To infer the file format, the file needs to have a file extension")]
     NoFileExtension,
+    #[error("Cannot parse OPB constraint {0:?}")]
+    InvalidOpbConstraint(String),
 }
 
+/// Parses an instance file, encoding all cardinality and pseudo-Boolean
+/// constraints to CNF
 pub fn parse<P: AsRef<Path>>(
     inst_path: P,
     file_format: FileFormat,
     opb_opts: fio::opb::Options,
 ) -> anyhow::Result<Parsed> {
-    let inst_path = inst_path.as_ref();
-    let inst: MultiOptInstance<VarManager> = match file_format {
-        FileFormat::Infer => {
-            if let Some(ext) = inst_path.extension() {
-                let path_without_compr = inst_path.with_extension("");
-                let ext = if is_one_of!(ext, "gz", "bz2", "xz") {
-                    // Strip compression extension
-                    match path_without_compr.extension() {
-                        Some(ext) => ext,
-                        None => anyhow::bail!(Error::NoFileExtension),
-                    }
-                } else {
-                    ext
-                };
-                if is_one_of!(ext, "mcnf", "bicnf", "wcnf", "cnf", "dimacs") {
-                    MultiOptInstance::from_dimacs_path(inst_path)?
-                } else if is_one_of!(ext, "opb", "mopb", "pbmo") {
-                    MultiOptInstance::from_opb_path(inst_path, opb_opts)?
-                } else {
-                    anyhow::bail!(Error::UnknownFileExtension(OsString::from(ext)))
-                }
-            } else {
-                anyhow::bail!(Error::NoFileExtension)
-            }
+    let (constrs, objs, mut vm) = read_constraints(inst_path.as_ref(), file_format, opb_opts)?;
+    let constrs = encode_constraints(constrs, &mut vm)?
+        .into_iter()
+        .map(Constraint::Clause)
+        .collect();
+    vm.mark_max_enc_var();
+    Ok(Parsed { constrs, objs, vm })
+}
+
+/// Parses an instance file, keeping cardinality and pseudo-Boolean
+/// constraints as native constraints. These are only encoded to CNF when
+/// initializing the solver, with certified encodings if proof logging.
+pub fn parse_native<P: AsRef<Path>>(
+    inst_path: P,
+    file_format: FileFormat,
+    opb_opts: fio::opb::Options,
+) -> anyhow::Result<Parsed> {
+    let (constrs, objs, mut vm) = read_constraints(inst_path.as_ref(), file_format, opb_opts)?;
+    vm.mark_max_enc_var();
+    Ok(Parsed { constrs, objs, vm })
+}
+
+/// Builder for instances that are constructed in memory rather than parsed
+/// from a file. The built instance is processed in the same way as a parsed
+/// one, e.g., with [`handle_soft_clauses`], [`max_pre`] and [`reindexing`].
+/// Variables that were not allocated through the builder are reserved when
+/// they are added.
+#[derive(Debug, Default, Clone)]
+pub struct InstanceBuilder {
+    constrs: Vec<Constraint>,
+    objs: Vec<Objective>,
+    vm: VarManager,
+}
+
+impl InstanceBuilder {
+    /// Creates a new builder for an empty instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocates a fresh variable
+    pub fn new_var(&mut self) -> Var {
+        self.vm.new_var()
+    }
+
+    /// Allocates a fresh variable and returns its positive literal
+    pub fn new_lit(&mut self) -> Lit {
+        self.vm.new_var().pos_lit()
+    }
+
+    /// Gets the variable manager for allocating variables, e.g., when
+    /// building constraints with RustSAT encodings
+    pub fn var_manager_mut(&mut self) -> &mut VarManager {
+        &mut self.vm
+    }
+
+    /// Adds a hard clause
+    pub fn add_clause(&mut self, clause: Clause) -> &mut Self {
+        clause.iter().for_each(|&lit| self.reserve(lit));
+        self.constrs.push(Constraint::Clause(clause));
+        self
+    }
+
+    /// Adds a hard cardinality constraint. The constraint is encoded to CNF
+    /// in the same way as the constraints of a parsed OPB file.
+    pub fn add_card_constraint(&mut self, constr: CardConstraint) -> &mut Self {
+        constr.iter().for_each(|&lit| self.reserve(lit));
+        self.constrs.push(Constraint::Card(constr));
+        self
+    }
+
+    /// Adds a hard pseudo-Boolean constraint. The constraint is encoded to
+    /// CNF in the same way as the constraints of a parsed OPB file.
+    pub fn add_pb_constraint(&mut self, constr: PbConstraint) -> &mut Self {
+        constr.iter().for_each(|&(lit, _)| self.reserve(lit));
+        self.constrs.push(Constraint::Pb(constr));
+        self
+    }
+
+    /// Adds an objective to minimize, given as weighted literals that incur
+    /// their weight when true, and a constant offset
+    pub fn add_objective<I: WLitIter>(&mut self, lits: I, offset: isize) -> &mut Self {
+        let mut obj = Objective::default();
+        for (lit, weight) in lits {
+            self.reserve(lit);
+            obj.add_soft_lit(weight, lit);
         }
+        obj.set_offset(offset);
+        self.objs.push(obj);
+        self
+    }
+
+    /// Adds an objective to minimize, given as weighted soft clauses and a
+    /// constant offset. The soft clauses are relaxed by
+    /// [`handle_soft_clauses`].
+    pub fn add_soft_clause_objective<I>(&mut self, soft_cls: I, offset: isize) -> &mut Self
+    where
+        I: IntoIterator<Item = (Clause, usize)>,
+    {
+        let mut obj = Objective::default();
+        for (cl, weight) in soft_cls {
+            cl.iter().for_each(|&lit| self.reserve(lit));
+            obj.add_soft_clause(weight, cl);
+        }
+        obj.set_offset(offset);
+        self.objs.push(obj);
+        self
+    }
+
+    /// Builds the instance, keeping cardinality and pseudo-Boolean
+    /// constraints native like [`parse_native`]
+    pub fn build(self) -> Parsed {
+        let InstanceBuilder {
+            constrs,
+            objs,
+            mut vm,
+        } = self;
+        vm.mark_max_orig_var();
+        vm.mark_max_enc_var();
+        Parsed { constrs, objs, vm }
+    }
+
+    /// Builds the instance, encoding all cardinality and pseudo-Boolean
+    /// constraints to CNF like [`parse`]
+    ///
+    /// # Errors
+    ///
+    /// If encoding a constraint fails.
+    pub fn build_cnf(self) -> anyhow::Result<Parsed> {
+        let InstanceBuilder {
+            constrs,
+            objs,
+            mut vm,
+        } = self;
+        vm.mark_max_orig_var();
+        let constrs = encode_constraints(constrs, &mut vm)?
+            .into_iter()
+            .map(Constraint::Clause)
+            .collect();
+        vm.mark_max_enc_var();
+        Ok(Parsed { constrs, objs, vm })
+    }
+
+    /// Reserves the variable of a literal that was not allocated through the
+    /// builder
+    fn reserve(&mut self, lit: Lit) {
+        self.vm.increase_next_free(lit.var() + 1);
+    }
+}
+
+fn read(
+    inst_path: &Path,
+    file_format: FileFormat,
+    opb_opts: fio::opb::Options,
+) -> anyhow::Result<MultiOptInstance<VarManager>> {
+    Ok(match file_format.resolve(inst_path)? {
         FileFormat::Dimacs => MultiOptInstance::from_dimacs_path(inst_path)?,
         FileFormat::Opb => MultiOptInstance::from_opb_path(inst_path, opb_opts)?,
-    };
-    // FIXME: make sure constraint order is preserved
+        FileFormat::Infer => unreachable!("the file format is resolved"),
+    })
+}
+
+/// Reads an instance file and splits it into its hard constraints, objectives
+/// and variable manager. The constraints are in the order of the file.
+fn read_constraints(
+    inst_path: &Path,
+    file_format: FileFormat,
+    opb_opts: fio::opb::Options,
+) -> anyhow::Result<(Vec<Constraint>, Vec<Objective>, VarManager)> {
+    let file_format = file_format.resolve(inst_path)?;
+    let inst = read(inst_path, file_format, opb_opts)?;
     let (mut constr, objs) = inst.decompose();
     constr.var_manager_mut().mark_max_orig_var();
-    let (cnf, mut vm) = constr.into_cnf();
-    vm.mark_max_enc_var();
-    Ok(Parsed { cnf, objs, vm })
+    let (cnf, _, _, vm) = constr.decompose();
+    let constrs = match file_format {
+        // RustSAT groups the constraints of OPB files by type, so they are
+        // read again line by line to keep the order of the file
+        FileFormat::Opb => read_opb_constraints(inst_path, opb_opts)?,
+        _ => cnf.into_iter().map(Constraint::Clause).collect(),
+    };
+    Ok((constrs, objs, vm))
+}
+
+/// Reads the constraints of an OPB file in the order of the file. Every
+/// constraint line becomes one constraint, so that the constraints are
+/// numbered in the same way as by VeriPB.
+fn read_opb_constraints(
+    inst_path: &Path,
+    opb_opts: fio::opb::Options,
+) -> anyhow::Result<Vec<Constraint>> {
+    let reader = fio::open_compressed_uncompressed_read(inst_path)?;
+    let mut constrs = vec![];
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if is_opb_constraint(&line) {
+            constrs.push(parse_opb_constraint(&line, opb_opts).with_context(|| {
+                format!(
+                    "failed to parse line {} of {}",
+                    idx + 1,
+                    inst_path.display()
+                )
+            })?);
+        }
+    }
+    Ok(constrs)
+}
+
+/// Checks whether a line of an OPB file holds a constraint rather than a
+/// comment or an objective
+fn is_opb_constraint(line: &str) -> bool {
+    let line = line.trim_start();
+    !line.is_empty() && !line.starts_with('*') && !line.starts_with("min:")
+}
+
+/// Parses a linear constraint line of an OPB file
+fn parse_opb_constraint(line: &str, opb_opts: fio::opb::Options) -> anyhow::Result<Constraint> {
+    let invalid = || Error::InvalidOpbConstraint(line.trim().to_string());
+    let constr = line.trim().strip_suffix(';').ok_or_else(invalid)?;
+    let (lhs, op, rhs) = ["<=", ">=", "="]
+        .into_iter()
+        .find_map(|op| constr.split_once(op).map(|(lhs, rhs)| (lhs, op, rhs)))
+        .ok_or_else(invalid)?;
+    let mut rhs: isize = rhs.trim().parse().map_err(|_| invalid())?;
+    let mut terms = vec![];
+    let mut tokens = lhs.split_whitespace();
+    while let Some(coeff) = tokens.next() {
+        let coeff: isize = coeff.parse().map_err(|_| invalid())?;
+        let lit = tokens.next().ok_or_else(invalid)?;
+        let (negated, var) = match lit.strip_prefix('~') {
+            Some(var) => (true, var),
+            None => (false, lit),
+        };
+        let idx: u32 = var
+            .strip_prefix('x')
+            .and_then(|idx| idx.parse().ok())
+            .and_then(|idx: u32| idx.checked_sub(opb_opts.first_var_idx))
+            .ok_or_else(invalid)?;
+        let lit = Lit::new(idx, negated);
+        // normalize to positive coefficients, `-c l` is `c ~l - c`
+        if coeff < 0 {
+            terms.push((!lit, -coeff));
+            rhs -= coeff;
+        } else {
+            terms.push((lit, coeff));
+        }
+    }
+    let is_card = terms.iter().all(|&(_, coeff)| coeff == 1);
+    Ok(match op {
+        ">=" if rhs > 0 && terms.iter().all(|&(_, coeff)| coeff >= rhs) => {
+            Constraint::Clause(terms.into_iter().map(|(lit, _)| lit).collect())
+        }
+        ">=" if is_card && rhs >= 0 => Constraint::Card(CardConstraint::new_lb(
+            terms.into_iter().map(|(lit, _)| lit),
+            rhs.unsigned_abs(),
+        )),
+        "<=" if is_card && rhs >= 0 => Constraint::Card(CardConstraint::new_ub(
+            terms.into_iter().map(|(lit, _)| lit),
+            rhs.unsigned_abs(),
+        )),
+        "=" if is_card && rhs >= 0 => Constraint::Card(CardConstraint::new_eq(
+            terms.into_iter().map(|(lit, _)| lit),
+            rhs.unsigned_abs(),
+        )),
+        ">=" => Constraint::Pb(PbConstraint::new_lb(terms, rhs)),
+        "<=" => Constraint::Pb(PbConstraint::new_ub(terms, rhs)),
+        _ => Constraint::Pb(PbConstraint::new_eq(terms, rhs)),
+    })
 }
 
-pub fn max_pre(parsed: Parsed, techniques: &str, reindexing: bool) -> (MaxPre, Instance) {
-    let Parsed { cnf, objs, .. } = parsed;
+/// Writes the hard constraints of an OPB instance file as the VeriPB input
+/// when proof logging. The file is copied without its objectives, so that the
+/// proof is checked against the constraints as they were given. The
+/// constraints are numbered in the same way by [`parse_native`].
+///
+/// # Errors
+///
+/// If the file is not an OPB file, or if reading or writing fails.
+pub fn write_opb_veripb_input<P: AsRef<Path>, W: io::Write>(
+    inst_path: P,
+    file_format: FileFormat,
+    writer: &mut W,
+) -> anyhow::Result<()> {
+    let inst_path = inst_path.as_ref();
+    anyhow::ensure!(
+        file_format.resolve(inst_path)? == FileFormat::Opb,
+        "the instance {} is not an OPB file",
+        inst_path.display()
+    );
+    let reader = fio::open_compressed_uncompressed_read(inst_path)?;
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim_start().starts_with("min:") {
+            writeln!(writer, "{line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Encodes native cardinality and pseudo-Boolean constraints to CNF, keeping
+/// the order of the constraints
+fn encode_constraints(constrs: Vec<Constraint>, vm: &mut VarManager) -> anyhow::Result<Cnf> {
+    let mut cnf = Cnf::new();
+    for constr in constrs {
+        match constr {
+            Constraint::Clause(cl) => cnf.add_clause(cl),
+            Constraint::Card(constr) => {
+                card::default_encode_cardinality_constraint(constr, &mut cnf, vm)
+                    .context("failed to encode cardinality constraint")?
+            }
+            Constraint::Pb(constr) => pb::default_encode_pb_constraint(constr, &mut cnf, vm)
+                .context("failed to encode pseudo-boolean constraint")?,
+        }
+    }
+    Ok(cnf)
+}
+
+/// Preprocesses the instance with MaxPre. Native constraints are encoded to CNF
+/// first, since MaxPre only handles clauses. MaxPre does not log its techniques
+/// to a proof, so preprocessed instances cannot be solved with proof logging.
+///
+/// # Errors
+///
+/// If encoding a native constraint fails.
+pub fn max_pre(
+    parsed: Parsed,
+    techniques: &str,
+    reindexing: bool,
+) -> anyhow::Result<(MaxPre, Instance)> {
+    let Parsed {
+        constrs,
+        objs,
+        mut vm,
+    } = parsed;
+    let cnf = encode_constraints(constrs, &mut vm)?;
     let mut prepro = MaxPre::new(
         cnf,
         objs.into_iter().map(|o| o.into_soft_cls()).collect(),
@@ -118,22 +458,32 @@ pub fn max_pre(parsed: Parsed, techniques: &str, reindexing: bool) -> (MaxPre, I
         cl.iter().fold(max, |max, l| cmp::max(max, l.var()))
     });
     let vm = VarManager::new(max_var, max_var);
-    (prepro, Instance { cnf, objs, vm })
+    Ok((
+        prepro,
+        Instance {
+            constrs: cnf.into_iter().map(Constraint::Clause).collect(),
+            objs,
+            vm,
+        },
+    ))
 }
 
 pub fn handle_soft_clauses(parsed: Parsed) -> Instance {
     let Parsed {
-        mut cnf,
+        mut constrs,
         objs,
         mut vm,
     } = parsed;
     let mut blits = RsHashMap::default();
+    let mut relaxed = Cnf::new();
     let objs: Vec<_> = objs
         .into_iter()
-        .map(|o| process_objective(o, &mut cnf, &mut blits, &mut vm))
+        .map(|o| process_objective(o, &mut relaxed, &mut blits, &mut vm))
         .collect();
     vm.mark_max_enc_var();
-    Instance { cnf, objs, vm }
+    // relaxed soft clauses come after the hard constraints
+    constrs.extend(relaxed.into_iter().map(Constraint::Clause));
+    Instance { constrs, objs, vm }
 }
 
 fn process_objective<Col: CollectClauses, VM: ManageVars>(
@@ -166,13 +516,19 @@ fn process_objective<Col: CollectClauses, VM: ManageVars>(
     (soft_lits, offset)
 }
 
-pub fn reindexing(inst: Instance) -> (Reindexer, Instance) {
+/// Reindexes the variables of the instance. Native constraints are encoded to
+/// CNF first.
+///
+/// # Errors
+///
+/// If encoding a native constraint fails.
+pub fn reindexing(inst: Instance) -> anyhow::Result<(Reindexer, Instance)> {
     let Instance {
-        mut cnf,
+        constrs,
         mut objs,
-        vm,
-        ..
+        mut vm,
     } = inst;
+    let mut cnf = encode_constraints(constrs, &mut vm)?;
     let mut reindexer = Reindexer::new(vm.max_orig_var());
     for (softs, _) in &mut objs {
         for (l, _) in softs {
@@ -186,5 +542,12 @@ pub fn reindexing(inst: Instance) -> (Reindexer, Instance) {
     }
     let max_var = reindexer.max_var().unwrap();
     let vm = VarManager::new(max_var, max_var);
-    (reindexer, Instance { cnf, objs, vm })
+    Ok((
+        reindexer,
+        Instance {
+            constrs: cnf.into_iter().map(Constraint::Clause).collect(),
+            objs,
+            vm,
+        },
+    ))
 }
@@ -7,8 +7,14 @@ use std::fmt;
 
 /// Early termination reasons for [`Solve::solve`]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Termination {
     /// Terminated because of maximum number of Pareto points reached
+    #[cfg_attr(feature = "serde", serde(rename = "pp-limit"))]
     PPLimit,
     /// Terminated because of maximum number of solutions reached
     SolsLimit,
@@ -18,6 +24,10 @@ pub enum Termination {
     OracleCallsLimit,
     /// Termination because of external interrupt
     Interrupted,
+    /// Terminated because the wall-clock or CPU time limit was reached
+    Timeout,
+    /// Terminated because the solution callback asked to stop
+    Callback,
 }
 
 impl fmt::Display for Termination {
@@ -38,6 +48,12 @@ impl fmt::Display for Termination {
             Termination::Interrupted => {
                 write!(f, "Solver terminated early because of interrupt signal")
             }
+            Termination::Timeout => {
+                write!(f, "Solver terminated early because of time limit")
+            }
+            Termination::Callback => {
+                write!(f, "Solver terminated early because of solution callback")
+            }
         }
     }
 }
@@ -3,18 +3,32 @@
 //! Shared types for the $P$-minimal solver.
 
 use std::{
-    cmp,
+    cmp, io,
     ops::{Index, Range},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use rustsat::{
     encodings::{card, pb, totdb, CollectCertClauses, CollectClauses},
-    instances::{Cnf, ManageVars, ReindexVars},
-    types::{Assignment, Clause, Lit, LitIter, RsHashMap, Var, WLitIter},
+    instances::{fio, ManageVars, ReindexVars},
+    types::{
+        constraints::{CardConstraint, PbConstraint},
+        Assignment, Clause, Lit, LitIter, RsHashMap, Var, WLitIter,
+    },
 };
 
+use crate::encodings::CertEncoding;
+
 /// The Pareto front of an instance. This is the return type of the solver.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ParetoFront<S = Assignment>
 where
     S: Clone + Eq,
@@ -54,6 +68,32 @@ where
     pub fn iter(&self) -> std::slice::Iter<'_, NonDomPoint<S>> {
         self.ndoms.iter()
     }
+
+    /// Merges multiple (partial) Pareto fronts into one. Points dominated by a
+    /// point of another front are filtered out, solutions of points with equal
+    /// costs are combined.
+    pub fn merge<I: IntoIterator<Item = ParetoFront<S>>>(fronts: I) -> Self {
+        let mut ndoms: Vec<NonDomPoint<S>> = vec![];
+        for ndom in fronts.into_iter().flat_map(|front| front.ndoms) {
+            if let Some(known) = ndoms.iter_mut().find(|known| known.costs == ndom.costs) {
+                for sol in ndom.sols {
+                    if !known.sols.contains(&sol) {
+                        known.sols.push(sol);
+                    }
+                }
+                continue;
+            }
+            if ndoms
+                .iter()
+                .any(|known| weakly_dominates(&known.costs, &ndom.costs))
+            {
+                continue;
+            }
+            ndoms.retain(|known| !weakly_dominates(&ndom.costs, &known.costs));
+            ndoms.push(ndom);
+        }
+        ParetoFront { ndoms }
+    }
 }
 
 impl<S: Clone + Eq> Index<usize> for ParetoFront<S> {
@@ -137,11 +177,13 @@ where
 /// tuple of costs. Multiple Pareto-optimal solutions can be associated with one
 /// non-dominated point.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonDomPoint<S = Assignment>
 where
     S: Clone + Eq,
 {
     costs: Vec<isize>,
+    #[cfg_attr(feature = "serde", serde(rename = "solutions"))]
     sols: Vec<S>,
 }
 
@@ -217,6 +259,188 @@ where
     }
 }
 
+/// An approximation of the Pareto front. This is what is known about the Pareto
+/// front when the solver terminates early. It consists of the non-dominated
+/// points proven so far, feasible cost points that are not (yet) proven to be
+/// non-dominated, and a lower bound set on the non-dominated points not found
+/// yet.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParetoApproximation {
+    /// Cost points proven to be non-dominated
+    proven: Vec<Vec<isize>>,
+    /// Feasible cost points not dominated by any other known point
+    feasible: Vec<Vec<isize>>,
+    /// Lower bound points, every non-dominated point that has not been found
+    /// yet is weakly dominated by at least one of these
+    lower_bounds: Vec<Vec<isize>>,
+    /// Whether the entire Pareto front has been found
+    complete: bool,
+}
+
+impl ParetoApproximation {
+    /// Adds a feasible cost point, unless it is weakly dominated by a known point
+    pub(crate) fn add_feasible(&mut self, costs: Vec<isize>) {
+        if self.iter().any(|known| weakly_dominates(known, &costs)) {
+            return;
+        }
+        self.feasible
+            .retain(|known| !weakly_dominates(&costs, known));
+        self.feasible.push(costs);
+    }
+
+    /// Adds a cost point proven to be non-dominated
+    pub(crate) fn add_proven(&mut self, costs: Vec<isize>) {
+        self.feasible
+            .retain(|known| !weakly_dominates(&costs, known));
+        self.proven.push(costs);
+    }
+
+    /// Sets the lower bound set
+    pub(crate) fn set_lower_bounds(&mut self, lower_bounds: Vec<Vec<isize>>) {
+        self.lower_bounds = lower_bounds;
+    }
+
+    /// Marks the Pareto front as fully discovered
+    pub(crate) fn set_complete(&mut self) {
+        self.complete = true;
+    }
+
+    /// Gets the cost points proven to be non-dominated
+    pub fn proven(&self) -> &[Vec<isize>] {
+        &self.proven
+    }
+
+    /// Gets the feasible cost points that are not proven to be non-dominated
+    pub fn feasible(&self) -> &[Vec<isize>] {
+        &self.feasible
+    }
+
+    /// Gets the lower bound set. Every non-dominated point that has not been
+    /// found yet is weakly dominated by at least one of the returned points.
+    pub fn lower_bounds(&self) -> &[Vec<isize>] {
+        &self.lower_bounds
+    }
+
+    /// Checks whether the approximation is the entire Pareto front
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Gets an iterator over all cost points of the approximation, proven
+    /// ones first
+    pub fn iter(&self) -> impl Iterator<Item = &Vec<isize>> {
+        self.proven.iter().chain(self.feasible.iter())
+    }
+
+    /// Gets an upper bound on the additive epsilon gap of the approximation.
+    /// For every non-dominated point `y`, the approximation contains a point
+    /// `a` with `a[i] - eps <= y[i]` for all objectives `i`. Returns [`None`] if
+    /// no bound is known, i.e., if there are no lower bounds or no points in
+    /// the approximation.
+    pub fn epsilon_gap(&self) -> Option<isize> {
+        if self.complete {
+            return Some(0);
+        }
+        if self.lower_bounds.is_empty() {
+            return None;
+        }
+        self.lower_bounds.iter().try_fold(0, |gap, lb| {
+            let eps = self
+                .iter()
+                .map(|costs| {
+                    costs
+                        .iter()
+                        .zip(lb)
+                        .map(|(&c, &l)| c - l)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .min()?;
+            Some(cmp::max(gap, eps))
+        })
+    }
+}
+
+/// A Pareto front shared between multiple solvers running in parallel.
+/// Connected solvers publish the non-dominated points they find and import the
+/// points published by the other solvers. Only cost points are shared, the
+/// solutions stay with the solver that found them.
+#[derive(Debug, Clone, Default)]
+pub struct SharedFront {
+    /// The published cost points together with the ID of the publishing solver
+    points: Arc<Mutex<Vec<(usize, Vec<isize>)>>>,
+    /// The number of solvers connected so far
+    n_connected: Arc<AtomicUsize>,
+}
+
+impl SharedFront {
+    /// Gets all cost points published so far
+    pub fn points(&self) -> Vec<Vec<isize>> {
+        self.points
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, costs)| costs.clone())
+            .collect()
+    }
+
+    /// Gets the number of cost points published so far
+    pub fn len(&self) -> usize {
+        self.points.lock().unwrap().len()
+    }
+
+    /// Checks if no cost points have been published yet
+    pub fn is_empty(&self) -> bool {
+        self.points.lock().unwrap().is_empty()
+    }
+
+    /// Connects a new solver to the shared front
+    pub(crate) fn connect(&self) -> FrontConnection {
+        FrontConnection {
+            front: self.clone(),
+            id: self.n_connected.fetch_add(1, Ordering::Relaxed),
+            n_seen: 0,
+        }
+    }
+}
+
+/// The connection of a single solver to a [`SharedFront`]
+#[derive(Debug)]
+pub(crate) struct FrontConnection {
+    /// The shared front
+    front: SharedFront,
+    /// The ID of the connected solver
+    id: usize,
+    /// The number of published points that have already been fetched
+    n_seen: usize,
+}
+
+impl FrontConnection {
+    /// Publishes a non-dominated point to the other solvers
+    pub fn publish(&self, costs: Vec<isize>) {
+        self.front.points.lock().unwrap().push((self.id, costs));
+    }
+
+    /// Fetches the points published by other solvers since the last fetch
+    pub fn fetch(&mut self) -> Vec<Vec<isize>> {
+        let points = self.front.points.lock().unwrap();
+        let new = points[self.n_seen..]
+            .iter()
+            .filter(|(id, _)| *id != self.id)
+            .map(|(_, costs)| costs.clone())
+            .collect();
+        self.n_seen = points.len();
+        new
+    }
+}
+
+/// Checks whether cost point `a` weakly dominates cost point `b`
+fn weakly_dominates(a: &[isize], b: &[isize]) -> bool {
+    debug_assert_eq!(a.len(), b.len());
+    a.iter().zip(b).all(|(a, b)| a <= b)
+}
+
 /// Data regarding an objective
 #[derive(Debug, Clone)]
 pub(crate) enum Objective {
@@ -532,8 +756,8 @@ where
 
 impl<PBE, CE> ObjEncoding<PBE, CE>
 where
-    PBE: pb::BoundUpperIncremental + pb::cert::BoundUpperIncremental,
-    CE: card::BoundUpperIncremental + card::cert::BoundUpperIncremental,
+    PBE: CertEncoding,
+    CE: CertEncoding,
 {
     /// Encodes the given range
     pub fn encode_ub_change_cert<Col, ProofW>(
@@ -588,46 +812,38 @@ where
         }
         Ok(())
     }
-}
 
-impl ObjEncoding<pb::DbGte, card::DbTotalizer> {
-    pub fn output_proof_details(&self, value: usize) -> (Lit, totdb::cert::SemDefs) {
+    pub fn output_proof_details(&self, value: usize) -> io::Result<(Lit, totdb::cert::SemDefs)> {
         match self {
-            ObjEncoding::Weighted(enc, offset) => {
-                enc.output_proof_details(value - *offset).unwrap()
-            }
-            ObjEncoding::Unweighted(enc, offset) => {
-                enc.output_proof_details(value - *offset).unwrap()
-            }
+            ObjEncoding::Weighted(enc, offset) => enc.output_proof_details(value - *offset),
+            ObjEncoding::Unweighted(enc, offset) => enc.output_proof_details(value - *offset),
             ObjEncoding::Constant => {
                 panic!("cannot get output proof details for constant objective")
             }
         }
     }
 
-    pub fn extend_assignment<'slf>(
-        &'slf self,
-        assign: &'slf Assignment,
-    ) -> impl Iterator<Item = Lit> + 'slf {
+    pub fn extend_assignment(&self, assign: &Assignment) -> io::Result<Vec<Lit>> {
         match self {
-            ObjEncoding::Weighted(enc, _) => enc.strictly_extend_assignment(assign),
-            ObjEncoding::Unweighted(enc, _) => enc.strictly_extend_assignment(assign),
-            ObjEncoding::Constant => None.into_iter().flatten(),
+            ObjEncoding::Weighted(enc, _) => enc.extend_assignment(assign),
+            ObjEncoding::Unweighted(enc, _) => enc.extend_assignment(assign),
+            ObjEncoding::Constant => Ok(vec![]),
         }
     }
 
     pub fn is_buffer_empty(&self) -> bool {
         match self {
             ObjEncoding::Weighted(enc, _) => enc.is_buffer_empty(),
-            ObjEncoding::Unweighted(_, _) | ObjEncoding::Constant => true,
+            ObjEncoding::Unweighted(enc, _) => enc.is_buffer_empty(),
+            ObjEncoding::Constant => true,
         }
     }
 
-    pub fn n_output_lits(&self) -> usize {
+    pub fn n_output_lits(&self) -> io::Result<usize> {
         match self {
             ObjEncoding::Weighted(enc, _) => enc.n_output_lits(),
             ObjEncoding::Unweighted(enc, _) => enc.n_output_lits(),
-            ObjEncoding::Constant => 0,
+            ObjEncoding::Constant => Ok(0),
         }
     }
 }
@@ -817,30 +1033,104 @@ impl ManageVars for Reindexer {
     }
 }
 
+/// A hard constraint passed to the solver. Cardinality and pseudo-Boolean
+/// constraints are encoded to clauses when initializing the solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// A clause
+    Clause(Clause),
+    /// A cardinality constraint
+    Card(CardConstraint),
+    /// A pseudo-Boolean constraint
+    Pb(PbConstraint),
+}
+
+impl From<Clause> for Constraint {
+    fn from(value: Clause) -> Self {
+        Constraint::Clause(value)
+    }
+}
+
+impl From<CardConstraint> for Constraint {
+    fn from(value: CardConstraint) -> Self {
+        Constraint::Card(value)
+    }
+}
+
+impl From<PbConstraint> for Constraint {
+    fn from(value: PbConstraint) -> Self {
+        Constraint::Pb(value)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Parsed {
-    pub(crate) cnf: Cnf,
+    /// The hard constraints in the order in which they were parsed or added
+    pub(crate) constrs: Vec<Constraint>,
     pub(crate) objs: Vec<rustsat::instances::Objective>,
     pub(crate) vm: VarManager,
 }
 
 #[derive(Debug, Clone)]
 pub struct Instance {
-    pub(crate) cnf: Cnf,
+    /// The hard constraints in the order in which they are numbered in the
+    /// proof
+    pub(crate) constrs: Vec<Constraint>,
     pub(crate) objs: Vec<(Vec<(Lit, usize)>, isize)>,
     pub(crate) vm: VarManager,
 }
 
 impl Instance {
     pub fn n_clauses(&self) -> usize {
-        self.cnf.n_clauses()
+        self.iter_clauses().count()
+    }
+
+    /// Gets the number of hard constraints, including native cardinality and
+    /// pseudo-Boolean constraints
+    pub fn n_constraints(&self) -> usize {
+        self.constrs.len()
     }
 
     pub fn n_objs(&self) -> usize {
         self.objs.len()
     }
 
-    pub fn iter_clauses(&self) -> std::slice::Iter<'_, Clause> {
-        self.cnf.iter()
+    pub fn iter_clauses(&self) -> impl Iterator<Item = &Clause> {
+        self.constrs.iter().filter_map(|constr| match constr {
+            Constraint::Clause(cl) => Some(cl),
+            _ => None,
+        })
+    }
+
+    /// Iterates over the hard constraints in the order in which they are
+    /// numbered in the proof
+    pub fn iter_constraints(&self) -> std::slice::Iter<'_, Constraint> {
+        self.constrs.iter()
+    }
+
+    /// Writes the hard constraints as an OPB file to be used as the VeriPB
+    /// input when proof logging. Native cardinality and pseudo-Boolean
+    /// constraints are written as they are, and the constraints are ordered
+    /// in the way they are numbered in the proof.
+    pub fn write_veripb_input<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let iter = self.constrs.iter().map(|constr| match constr {
+            Constraint::Clause(cl) => fio::opb::FileLine::<Option<_>>::Clause(cl.clone()),
+            Constraint::Card(constr) => fio::opb::FileLine::Card(constr.clone()),
+            Constraint::Pb(constr) => fio::opb::FileLine::Pb(constr.clone()),
+        });
+        fio::opb::write_opb_lines(writer, iter, fio::opb::Options::default())
+    }
+
+    /// Splits the instance into its hard constraints, objectives and variable
+    /// manager. The constraints are in the order in which they are numbered
+    /// in the proof.
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        impl Iterator<Item = Constraint>,
+        Vec<(Vec<(Lit, usize)>, isize)>,
+        VarManager,
+    ) {
+        (self.constrs.into_iter(), self.objs, self.vm)
     }
 }
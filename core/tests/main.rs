@@ -1,3 +1,29 @@
+/// Parses an instance and turns its soft clauses into objectives
+fn load(path: &str) -> scuttle_core::types::Instance {
+    scuttle_core::prepro::handle_soft_clauses(
+        scuttle_core::prepro::parse(
+            path,
+            scuttle_core::prepro::FileFormat::Infer,
+            rustsat::instances::fio::opb::Options::default(),
+        )
+        .unwrap(),
+    )
+}
+
+/// Parses an instance and initializes a solver on it
+fn init<S: scuttle_core::InitDefaultBlock>(path: &str, opts: scuttle_core::KernelOptions) -> S {
+    S::from_instance_default_blocking(load(path), opts).unwrap()
+}
+
+type Pmin = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+type Lb = scuttle_core::LowerBounding<rustsat_cadical::CaDiCaL<'static, 'static>>;
+type Bos = scuttle_core::BiOptSat<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+/// Initializes a p-minimal solver on the small instance
+fn small_pmin() -> Pmin {
+    init("./data/small.mcnf", Default::default())
+}
+
 macro_rules! check_pf_shape {
     ($pf:expr, $t:expr) => {{
         let pps_set: rustsat::types::RsHashSet<(Vec<isize>, usize)> = $pf
@@ -958,3 +984,1057 @@ mod bioptsat {
         }
     );
 }
+
+mod divcon {
+    type S = scuttle_core::DivCon<rustsat_cadical::CaDiCaL<'static, 'static>>;
+    generate_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    generate_tests!(
+        cb,
+        super::S,
+        scuttle_core::KernelOptions::default(),
+        scuttle_core::CoreBoostingOptions::default()
+    );
+
+    #[test]
+    fn anchor_too_small() {
+        use scuttle_core::{
+            options::{DivConAnchor, DivConOptions, SubProblemSize},
+            InitDefaultBlock, Limits, MaybeTerminatedError, Solve,
+        };
+        let inst = crate::load("./data/small.mcnf");
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.set_options(DivConOptions {
+            anchor: DivConAnchor::PMinimal(SubProblemSize::Smaller(5)),
+            ..Default::default()
+        });
+        assert!(matches!(
+            solver.solve(Limits::none()),
+            MaybeTerminatedError::Error(_)
+        ));
+    }
+}
+
+mod time_limits {
+    use std::time::Duration;
+
+    use scuttle_core::{
+        CoreBoost, CoreBoostingOptions, InitDefaultBlock, Limits, MaybeTerminatedError, Termination,
+    };
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    #[test]
+    fn core_boosting() {
+        let inst = crate::load("./data/small.mcnf");
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        let limits = Limits {
+            time: Some(Duration::ZERO),
+            ..Limits::none()
+        };
+        assert!(matches!(
+            solver.core_boost_with_limits(CoreBoostingOptions::default(), limits),
+            MaybeTerminatedError::Terminated(Termination::Timeout)
+        ));
+    }
+}
+
+mod approximation {
+    use scuttle_core::{InitDefaultBlock, KernelFunctions, Limits, MaybeTerminatedError, Solve};
+
+    fn early_termination<S: InitDefaultBlock + Solve>() {
+        let inst = crate::load("./data/small.mcnf");
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        let limits = Limits {
+            pps: Some(1),
+            ..Limits::none()
+        };
+        assert!(matches!(
+            solver.solve(limits),
+            MaybeTerminatedError::Terminated(_)
+        ));
+        let approx = solver.approximation();
+        assert!(!approx.is_complete());
+        assert_eq!(approx.proven().len(), 1);
+        let gap = approx.epsilon_gap().unwrap();
+        assert!(gap > 0);
+        // the remaining non-dominated points must be within the gap
+        let remaining: Vec<Vec<isize>> = vec![vec![0, 4], vec![2, 2], vec![4, 0]];
+        for y in remaining {
+            assert!(approx
+                .iter()
+                .any(|a| a.iter().zip(&y).all(|(&a, &y)| a - gap <= y)));
+        }
+    }
+
+    fn complete<S: InitDefaultBlock + Solve>() {
+        let inst = crate::load("./data/small.mcnf");
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.solve(Limits::none()).unwrap();
+        let approx = solver.approximation();
+        assert!(approx.is_complete());
+        assert_eq!(approx.proven().len(), 3);
+        assert!(approx.feasible().is_empty());
+        assert_eq!(approx.epsilon_gap(), Some(0));
+    }
+
+    #[test]
+    fn pmin() {
+        early_termination::<scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+
+    #[test]
+    fn lb() {
+        early_termination::<scuttle_core::LowerBounding<rustsat_cadical::CaDiCaL<'static, 'static>>>(
+        )
+    }
+
+    #[test]
+    fn pmin_complete() {
+        complete::<scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+
+    #[test]
+    fn lb_complete() {
+        complete::<scuttle_core::LowerBounding<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+
+    #[test]
+    fn bioptsat_complete() {
+        complete::<scuttle_core::BiOptSat<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+
+    #[test]
+    fn divcon_complete() {
+        complete::<scuttle_core::DivCon<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+}
+
+mod resume {
+    use scuttle_core::{
+        options::EnumOptions, InitDefaultBlock, KernelFunctions, KernelOptions, Limits,
+        MaybeTerminatedError, Solve,
+    };
+
+    use crate::{Bos, Lb, Pmin};
+
+    /// Solves in slices under the given limits and compares to a single run
+    fn sliced<S: InitDefaultBlock + Solve>(path: &str, opts: KernelOptions, limits: Limits) {
+        let mut full = crate::init::<S>(path, opts);
+        full.solve(Limits::none()).unwrap();
+        let expected: Vec<_> = full
+            .pareto_front()
+            .into_iter()
+            .map(|pp| (pp.costs().clone(), pp.n_sols()))
+            .collect();
+
+        let mut solver = crate::init::<S>(path, opts);
+        let mut n_slices = 0;
+        loop {
+            match solver.solve(limits) {
+                MaybeTerminatedError::Done(_) => break,
+                MaybeTerminatedError::Terminated(_) => (),
+                MaybeTerminatedError::Error(err) => panic!("solving failed: {err}"),
+            }
+            n_slices += 1;
+            assert!(n_slices < 10_000, "solving does not make progress");
+        }
+        assert!(n_slices > 0);
+        assert!(solver.approximation().is_complete());
+        let pf = solver.pareto_front();
+        assert_eq!(pf.len(), expected.len());
+        check_pf_shape!(pf, expected);
+    }
+
+    fn all_slicings<S: InitDefaultBlock + Solve>(path: &str, oracle_calls: usize) {
+        let none = Limits::none();
+        sliced::<S>(
+            path,
+            KernelOptions::default(),
+            Limits {
+                pps: Some(1),
+                ..none
+            },
+        );
+        sliced::<S>(
+            path,
+            KernelOptions::default(),
+            Limits {
+                sols: Some(1),
+                ..none
+            },
+        );
+        sliced::<S>(
+            path,
+            KernelOptions::default(),
+            Limits {
+                candidates: Some(1),
+                ..none
+            },
+        );
+        sliced::<S>(
+            path,
+            KernelOptions::default(),
+            Limits {
+                oracle_calls: Some(oracle_calls),
+                ..none
+            },
+        );
+    }
+
+    /// Solves in slices while enumerating all solutions at each non-dominated
+    /// point. Since a point at which solving is terminated while enumerating is
+    /// closed with the solutions found so far, only limits that are checked
+    /// between points are used.
+    fn enum_slicings<S: InitDefaultBlock + Solve>(path: &str) {
+        let mut opts = KernelOptions::default();
+        opts.set_enumeration(EnumOptions::Solutions(None));
+        let none = Limits::none();
+        sliced::<S>(
+            path,
+            opts,
+            Limits {
+                pps: Some(1),
+                ..none
+            },
+        );
+        sliced::<S>(
+            path,
+            opts,
+            Limits {
+                candidates: Some(1),
+                ..none
+            },
+        );
+    }
+
+    #[test]
+    fn pmin_medium() {
+        all_slicings::<Pmin>("./data/medium.mcnf", 1)
+    }
+
+    #[test]
+    fn pmin_four() {
+        all_slicings::<Pmin>("./data/four.mcnf", 1)
+    }
+
+    #[test]
+    fn lb_medium() {
+        all_slicings::<Lb>("./data/medium.mcnf", 1)
+    }
+
+    #[test]
+    fn lb_four() {
+        all_slicings::<Lb>("./data/four.mcnf", 1)
+    }
+
+    #[test]
+    fn bioptsat_small() {
+        // the optimality of the increasing objective is re-established after
+        // resuming, so each slice needs two oracle calls to make progress
+        all_slicings::<Bos>("./data/small.mcnf", 2)
+    }
+
+    #[test]
+    fn bioptsat_medium() {
+        all_slicings::<Bos>("./data/medium.mcnf", 2)
+    }
+
+    #[test]
+    fn pmin_medium_all_sols() {
+        enum_slicings::<Pmin>("./data/medium.mcnf")
+    }
+
+    #[test]
+    fn lb_medium_all_sols() {
+        enum_slicings::<Lb>("./data/medium.mcnf")
+    }
+
+    #[test]
+    fn bioptsat_medium_all_sols() {
+        enum_slicings::<Bos>("./data/medium.mcnf")
+    }
+}
+
+mod checkpoint {
+    use scuttle_core::{
+        checkpoint::{AlgState, Progress},
+        Checkpoint, InitDefaultBlock, KernelFunctions, Limits, MaybeTerminatedError, Solve,
+    };
+
+    use crate::{Bos, Lb, Pmin};
+
+    /// Solves one non-dominated point at a time, restoring the progress in a
+    /// fresh solver every time, and compares to a single run
+    fn restored<S: InitDefaultBlock + Solve + Checkpoint>(path: &str) {
+        let mut full = crate::init::<S>(path, Default::default());
+        full.solve(Limits::none()).unwrap();
+        let expected: Vec<_> = full
+            .pareto_front()
+            .into_iter()
+            .map(|pp| (pp.costs().clone(), pp.n_sols()))
+            .collect();
+
+        let limits = Limits {
+            pps: Some(1),
+            ..Limits::none()
+        };
+        let mut progress: Option<Progress> = None;
+        let mut n_restores = 0;
+        let solver = loop {
+            let mut solver = crate::init::<S>(path, Default::default());
+            if let Some(progress) = progress.take() {
+                solver.restore_progress(progress).unwrap();
+            }
+            match solver.solve(limits) {
+                MaybeTerminatedError::Done(_) => break solver,
+                MaybeTerminatedError::Terminated(_) => (),
+                MaybeTerminatedError::Error(err) => panic!("solving failed: {err}"),
+            }
+            progress = Some(solver.progress());
+            n_restores += 1;
+            assert!(n_restores < 1_000, "solving does not make progress");
+        };
+        assert!(n_restores > 0);
+        assert!(solver.approximation().is_complete());
+        let pf = solver.pareto_front();
+        assert_eq!(pf.len(), expected.len());
+        check_pf_shape!(pf, expected);
+    }
+
+    #[test]
+    fn pmin_medium() {
+        restored::<Pmin>("./data/medium.mcnf")
+    }
+
+    #[test]
+    fn pmin_four() {
+        restored::<Pmin>("./data/four.mcnf")
+    }
+
+    #[test]
+    fn lb_medium() {
+        restored::<Lb>("./data/medium.mcnf")
+    }
+
+    #[test]
+    fn lb_four() {
+        restored::<Lb>("./data/four.mcnf")
+    }
+
+    #[test]
+    fn bioptsat_small() {
+        restored::<Bos>("./data/small.mcnf")
+    }
+
+    #[test]
+    fn bioptsat_medium() {
+        restored::<Bos>("./data/medium.mcnf")
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut solver = crate::init::<Pmin>("./data/small.mcnf", Default::default());
+        assert!(matches!(
+            solver.solve(Limits {
+                pps: Some(1),
+                ..Limits::none()
+            }),
+            MaybeTerminatedError::Terminated(_)
+        ));
+        let progress = solver.progress();
+        assert_eq!(progress.state, AlgState::PMinimal);
+        let mut solver = crate::init::<Lb>("./data/small.mcnf", Default::default());
+        assert!(solver.restore_progress(progress).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn file() {
+        let mut solver = crate::init::<Lb>("./data/medium.mcnf", Default::default());
+        assert!(matches!(
+            solver.solve(Limits {
+                pps: Some(2),
+                ..Limits::none()
+            }),
+            MaybeTerminatedError::Terminated(_)
+        ));
+        let path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+        solver.checkpoint(&path).unwrap();
+        assert_eq!(Progress::read(&path).unwrap(), solver.progress());
+        let mut restored = crate::init::<Lb>("./data/medium.mcnf", Default::default());
+        restored.restore(&path).unwrap();
+        assert_eq!(restored.pareto_front().len(), 2);
+        restored.solve(Limits::none()).unwrap();
+        assert!(restored.approximation().is_complete());
+    }
+}
+
+mod warm_start {
+    use rustsat::{lit, types::Assignment};
+    use scuttle_core::{InitDefaultBlock, KernelFunctions, Limits, Solve, WarmStart};
+
+    use crate::{Bos, Lb, Pmin};
+
+    /// Seeds the search with the first `n_seeds` solutions of a previous run
+    /// and compares to the previous run
+    fn seeded<S: InitDefaultBlock + Solve + WarmStart>(path: &str, n_seeds: usize) {
+        let mut full = crate::init::<S>(path, Default::default());
+        full.solve(Limits::none()).unwrap();
+        let pf = full.pareto_front();
+        let expected: Vec<_> = pf
+            .iter()
+            .map(|pp| (pp.costs().clone(), pp.n_sols()))
+            .collect();
+        let seeds: Vec<_> = pf
+            .into_iter()
+            .flat_map(|pp| pp.into_iter())
+            .take(n_seeds)
+            .collect();
+        let n_seeds = seeds.len();
+
+        let mut solver = crate::init::<S>(path, Default::default());
+        let costs = solver.warm_start(seeds).unwrap();
+        assert_eq!(costs.len(), n_seeds);
+        solver.solve(Limits::none()).unwrap();
+        assert!(solver.approximation().is_complete());
+        let pf = solver.pareto_front();
+        assert_eq!(pf.len(), expected.len());
+        check_pf_shape!(pf, expected);
+    }
+
+    #[test]
+    fn pmin_medium() {
+        seeded::<Pmin>("./data/medium.mcnf", 1);
+        seeded::<Pmin>("./data/medium.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn pmin_four() {
+        seeded::<Pmin>("./data/four.mcnf", 1);
+        seeded::<Pmin>("./data/four.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn lb_medium() {
+        seeded::<Lb>("./data/medium.mcnf", 1);
+        seeded::<Lb>("./data/medium.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn lb_four() {
+        seeded::<Lb>("./data/four.mcnf", 1);
+        seeded::<Lb>("./data/four.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn bioptsat_small() {
+        seeded::<Bos>("./data/small.mcnf", 1);
+        seeded::<Bos>("./data/small.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn bioptsat_medium() {
+        seeded::<Bos>("./data/medium.mcnf", 1);
+        seeded::<Bos>("./data/medium.mcnf", usize::MAX);
+    }
+
+    #[test]
+    fn infeasible() {
+        let mut solver = crate::init::<Pmin>("./data/small.mcnf", Default::default());
+        let sol: Assignment = [!lit![0], !lit![1], !lit![2], !lit![3]]
+            .into_iter()
+            .collect();
+        assert!(solver.warm_start([sol]).is_err());
+    }
+
+    #[test]
+    fn partial() {
+        let mut solver = crate::init::<Pmin>("./data/small.mcnf", Default::default());
+        let sol: Assignment = [lit![0], lit![1], lit![2]].into_iter().collect();
+        assert!(solver.warm_start([sol]).is_err());
+    }
+
+    #[test]
+    fn dominated_costs() {
+        let mut solver = crate::init::<Pmin>("./data/small.mcnf", Default::default());
+        let sol: Assignment = [lit![0], lit![1], lit![2], lit![3]].into_iter().collect();
+        assert_eq!(solver.warm_start([sol]).unwrap(), vec![vec![4, 4]]);
+    }
+
+    #[test]
+    fn non_unit_soft_clauses() {
+        let mut solver = crate::init::<Pmin>("./data/soft-clauses.mcnf", Default::default());
+        let sols: Vec<Assignment> = vec![
+            [lit![0], lit![1], !lit![2], !lit![3]].into_iter().collect(),
+            [!lit![0], lit![1], lit![2], !lit![3]].into_iter().collect(),
+            [lit![0], !lit![1], !lit![2], !lit![3]]
+                .into_iter()
+                .collect(),
+        ];
+        assert_eq!(
+            solver.warm_start(sols).unwrap(),
+            vec![vec![2, 0], vec![0, 1], vec![1, 1]]
+        );
+        solver.solve(Limits::none()).unwrap();
+        check_pf_shape!(solver.pareto_front(), vec![(vec![0, 0], 1)]);
+    }
+}
+
+mod lexicographic {
+    use scuttle_core::{options::LexicographicOptions, InitDefaultBlock, KernelFunctions, Solve};
+
+    type S = scuttle_core::Lexicographic<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    fn lex_optimum(inst_path: &str, opts: LexicographicOptions) -> Vec<isize> {
+        let inst = crate::load(inst_path);
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.set_options(opts);
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        // only one point is proven, the rest of the Pareto front is unknown
+        let approx = solver.approximation();
+        assert!(!approx.is_complete());
+        assert_eq!(approx.proven().len(), 1);
+        let pf = solver.pareto_front();
+        assert_eq!(pf.len(), 1);
+        assert_eq!(pf[0].n_sols(), 1);
+        pf[0].costs().clone()
+    }
+
+    #[test]
+    fn small() {
+        assert_eq!(
+            lex_optimum("./data/small.mcnf", LexicographicOptions::default()),
+            vec![0, 4]
+        );
+    }
+
+    #[test]
+    fn small_priority() {
+        let opts = LexicographicOptions {
+            priority: vec![1],
+            ..Default::default()
+        };
+        assert_eq!(lex_optimum("./data/small.mcnf", opts), vec![4, 0]);
+    }
+
+    #[test]
+    fn small_core_guided() {
+        let opts = LexicographicOptions {
+            priority: vec![1, 0],
+            core_guided: true,
+        };
+        assert_eq!(lex_optimum("./data/small.mcnf", opts), vec![4, 0]);
+    }
+
+    #[test]
+    fn four() {
+        let opts = LexicographicOptions {
+            priority: vec![3, 1],
+            ..Default::default()
+        };
+        assert_eq!(lex_optimum("./data/four.mcnf", opts), vec![0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn four_core_guided() {
+        let opts = LexicographicOptions {
+            priority: vec![3, 1],
+            core_guided: true,
+        };
+        assert_eq!(lex_optimum("./data/four.mcnf", opts), vec![0, 0, 1, 0]);
+    }
+}
+
+mod portfolio {
+    use scuttle_core::{
+        portfolio::{Portfolio, PortfolioAlg, PortfolioConfig},
+        Limits,
+    };
+
+    #[test]
+    fn complete() {
+        let portfolio: Portfolio = Portfolio::new(vec![
+            PortfolioConfig::new(PortfolioAlg::PMinimal),
+            PortfolioConfig::new(PortfolioAlg::BiOptSat),
+            PortfolioConfig::new(PortfolioAlg::LowerBounding),
+        ]);
+        let res = portfolio
+            .solve(crate::load("./data/small.mcnf"), Limits::none())
+            .unwrap();
+        assert!(res.winner.is_some());
+        assert_eq!(res.stats.len(), 3);
+        check_pf_shape!(
+            res.pareto_front,
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn merge_partial() {
+        let portfolio: Portfolio = Portfolio::new(vec![
+            PortfolioConfig::new(PortfolioAlg::PMinimal),
+            PortfolioConfig::new(PortfolioAlg::LowerBounding),
+        ]);
+        let limits = Limits {
+            pps: Some(1),
+            ..Limits::none()
+        };
+        let res = portfolio
+            .solve(crate::load("./data/small.mcnf"), limits)
+            .unwrap();
+        assert!(res.winner.is_none());
+        assert!(!res.pareto_front.is_empty());
+        let front: Vec<Vec<isize>> = vec![vec![0, 4], vec![2, 2], vec![4, 0]];
+        for pp in &res.pareto_front {
+            assert!(front.contains(pp.costs()));
+        }
+    }
+
+    #[test]
+    fn cooperative() {
+        let mut portfolio: Portfolio = Portfolio::new(vec![
+            PortfolioConfig::new(PortfolioAlg::PMinimal),
+            PortfolioConfig::new(PortfolioAlg::LowerBounding),
+        ]);
+        portfolio.set_cooperative(true);
+        let res = portfolio
+            .solve(crate::load("./data/small.mcnf"), Limits::none())
+            .unwrap();
+        assert!(res.winner.is_some());
+        check_pf_shape!(
+            res.pareto_front,
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn bioptsat_non_biobjective() {
+        let portfolio: Portfolio =
+            Portfolio::new(vec![PortfolioConfig::new(PortfolioAlg::BiOptSat)]);
+        let inst = crate::load("./data/four.mcnf");
+        assert!(portfolio.solve(inst, Limits::none()).is_err());
+    }
+}
+
+mod cooperation {
+    use scuttle_core::{
+        types::SharedFront, Cooperate, InitDefaultBlock, KernelFunctions, Limits,
+        MaybeTerminatedError, Solve,
+    };
+
+    use crate::Pmin;
+
+    fn import<S: InitDefaultBlock + Solve + Cooperate>() {
+        let inst = crate::load("./data/medium.mcnf");
+        let front = SharedFront::default();
+        let mut first =
+            Pmin::from_instance_default_blocking(inst.clone(), Default::default()).unwrap();
+        first.share_front(&front).unwrap();
+        let limits = Limits {
+            pps: Some(3),
+            ..Limits::none()
+        };
+        assert!(matches!(
+            first.solve(limits),
+            MaybeTerminatedError::Terminated(_)
+        ));
+        assert_eq!(front.len(), 3);
+        let mut second = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        second.share_front(&front).unwrap();
+        second.solve(Limits::none()).unwrap();
+        // the second solver only finds the points the first one did not
+        assert_eq!(second.pareto_front().len(), 3);
+        assert_eq!(front.len(), 6);
+        let approx = second.approximation();
+        assert!(approx.is_complete());
+        assert_eq!(approx.proven().len(), 6);
+    }
+
+    #[test]
+    fn pmin() {
+        import::<Pmin>()
+    }
+
+    #[test]
+    fn lb() {
+        import::<scuttle_core::LowerBounding<rustsat_cadical::CaDiCaL<'static, 'static>>>()
+    }
+}
+
+mod encodings {
+    use rustsat::encodings::{card::DbTotalizer, pb};
+    use scuttle_core::encodings::CardinalityNetwork;
+
+    type Oracle = rustsat_cadical::CaDiCaL<'static, 'static>;
+
+    mod pmin_dpw {
+        type S = scuttle_core::PMinimal<
+            super::Oracle,
+            super::pb::DynamicPolyWatchdog,
+            super::CardinalityNetwork,
+        >;
+        generate_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+
+    mod lb_adder {
+        type S =
+            scuttle_core::LowerBounding<super::Oracle, super::pb::BinaryAdder, super::DbTotalizer>;
+        generate_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+
+    mod bioptsat_cardnet {
+        type S = scuttle_core::BiOptSat<super::Oracle, super::pb::DbGte, super::CardinalityNetwork>;
+        generate_biobj_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+}
+
+mod native_constraints {
+    use scuttle_core::{prepro, InitDefaultBlock, KernelFunctions, Solve};
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    #[test]
+    fn dal() {
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse_native(
+                "./data/dal.opb",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        assert!(inst.n_constraints() > inst.n_clauses());
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        assert!(solver.approximation().is_complete());
+        assert_eq!(solver.pareto_front().len(), 21);
+    }
+
+    #[test]
+    fn file_order() {
+        use scuttle_core::types::Constraint;
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse_native(
+                "./data/mixed.opb",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let constrs: Vec<_> = inst.iter_constraints().collect();
+        assert_eq!(constrs.len(), 4);
+        assert!(matches!(constrs[0], Constraint::Pb(_)));
+        assert!(matches!(constrs[1], Constraint::Clause(_)));
+        assert!(matches!(constrs[2], Constraint::Card(_)));
+        assert!(matches!(constrs[3], Constraint::Clause(_)));
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        check_pf_shape!(solver.pareto_front(), vec![(vec![1, 0], 1)]);
+    }
+}
+
+mod builder {
+    use rustsat::clause;
+    use scuttle_core::{prepro, InitDefaultBlock, KernelFunctions, Solve};
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    #[test]
+    fn small() {
+        // same instance as `small.mcnf`
+        let mut builder = prepro::InstanceBuilder::new();
+        let lits: Vec<_> = (0..4).map(|_| builder.new_lit()).collect();
+        builder
+            .add_clause(clause![lits[0], lits[1]])
+            .add_clause(clause![lits[1], lits[2]])
+            .add_clause(clause![lits[2], lits[3]])
+            .add_objective([(lits[1], 2), (lits[3], 2)], 0)
+            .add_soft_clause_objective([(clause![!lits[0]], 2), (clause![!lits[2]], 2)], 0);
+        let inst = prepro::handle_soft_clauses(builder.build());
+        let mut solver = S::from_instance_default_blocking(inst, Default::default()).unwrap();
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn constraint_order() {
+        use rustsat::types::constraints::CardConstraint;
+        use scuttle_core::types::Constraint;
+
+        let mut builder = prepro::InstanceBuilder::new();
+        let lits: Vec<_> = (0..3).map(|_| builder.new_lit()).collect();
+        builder
+            .add_clause(clause![lits[0], lits[1]])
+            .add_card_constraint(CardConstraint::new_ub(lits.clone(), 1))
+            .add_clause(clause![lits[1], lits[2]])
+            .add_objective([(lits[0], 1)], 0);
+        let inst = prepro::handle_soft_clauses(builder.build());
+        let constrs: Vec<_> = inst.iter_constraints().collect();
+        assert_eq!(constrs.len(), 3);
+        assert_eq!(constrs[0], &Constraint::Clause(clause![lits[0], lits[1]]));
+        assert!(matches!(constrs[1], Constraint::Card(_)));
+        assert_eq!(constrs[2], &Constraint::Clause(clause![lits[1], lits[2]]));
+    }
+
+    #[test]
+    fn reserve_constraint_vars() {
+        use rustsat::{
+            lit,
+            types::{
+                constraints::{CardConstraint, PbConstraint},
+                Var,
+            },
+        };
+
+        let mut builder = prepro::InstanceBuilder::new();
+        builder.add_card_constraint(CardConstraint::new_lb([lit![0], lit![1]], 1));
+        builder.add_pb_constraint(PbConstraint::new_ub([(lit![2], 2), (lit![3], 1)], 2));
+        assert_eq!(builder.new_var(), Var::new(4));
+    }
+}
+
+mod solver_builder {
+    use rustsat::{lit, types::Assignment};
+    #[cfg(feature = "minisat")]
+    use scuttle_core::builder::{CadicalConfig, OracleBackend};
+    use scuttle_core::{
+        builder::{Algorithm, CardEncoding, PbEncoding},
+        CoreBoostingOptions, Limits, MaybeTerminatedError, SolverBuilder,
+    };
+
+    #[test]
+    fn encodings() {
+        let mut builder = SolverBuilder::new(Algorithm::LowerBounding);
+        builder.set_encodings(PbEncoding::Dpw, CardEncoding::CardNet);
+        let mut solver = builder.build(crate::load("./data/small.mcnf")).unwrap();
+        assert!(solver.core_boost(Limits::none()).unwrap());
+        solver.solve(Limits::none()).unwrap();
+        assert!(solver.approximation().is_complete());
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn core_boosting() {
+        let mut builder = SolverBuilder::new(Algorithm::PMinimal);
+        builder.set_core_boosting(Some(CoreBoostingOptions::default()));
+        let mut solver = builder.build(crate::load("./data/small.mcnf")).unwrap();
+        assert!(solver.core_boost(Limits::none()).unwrap());
+        solver.solve(Limits::none()).unwrap();
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut builder = SolverBuilder::new(Algorithm::PMinimal);
+        builder
+            .set_encodings(PbEncoding::Adder, CardEncoding::Tot)
+            .set_core_boosting(Some(CoreBoostingOptions::default()));
+        assert!(builder.build(crate::load("./data/small.mcnf")).is_err());
+    }
+
+    #[test]
+    fn checkpoint() {
+        let mut solver = SolverBuilder::new(Algorithm::BiOptSat)
+            .build(crate::load("./data/small.mcnf"))
+            .unwrap();
+        assert!(matches!(
+            solver.solve(Limits {
+                pps: Some(1),
+                ..Limits::none()
+            }),
+            MaybeTerminatedError::Terminated(_)
+        ));
+        let progress = solver.progress().unwrap();
+        let mut solver = SolverBuilder::new(Algorithm::BiOptSat)
+            .build(crate::load("./data/small.mcnf"))
+            .unwrap();
+        solver.restore_progress(progress).unwrap();
+        solver.solve(Limits::none()).unwrap();
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn warm_start() {
+        let mut solver = SolverBuilder::new(Algorithm::PMinimal)
+            .build(crate::load("./data/small.mcnf"))
+            .unwrap();
+        let sol: Assignment = [lit![0], lit![1], lit![2], lit![3]].into_iter().collect();
+        assert_eq!(solver.warm_start(vec![sol]).unwrap(), vec![vec![4, 4]]);
+        solver.solve(Limits::none()).unwrap();
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[test]
+    fn unseeded() {
+        let mut solver = SolverBuilder::new(Algorithm::DivCon(Default::default()))
+            .build(crate::load("./data/small.mcnf"))
+            .unwrap();
+        assert!(solver.progress().is_err());
+        assert!(solver.warm_start(vec![]).is_err());
+    }
+
+    #[cfg(feature = "minisat")]
+    #[test]
+    fn minisat() {
+        let mut builder = SolverBuilder::new(Algorithm::PMinimal);
+        builder.set_oracle(OracleBackend::Minisat);
+        let mut solver = builder.build(crate::load("./data/small.mcnf")).unwrap();
+        solver.solve(Limits::none()).unwrap();
+        check_pf_shape!(
+            solver.pareto_front(),
+            vec![(vec![0, 4], 1), (vec![2, 2], 1), (vec![4, 0], 1)]
+        );
+    }
+
+    #[cfg(feature = "minisat")]
+    #[test]
+    fn minisat_cadical_config() {
+        let mut builder = SolverBuilder::new(Algorithm::PMinimal);
+        builder
+            .set_oracle(OracleBackend::Minisat)
+            .set_cadical_config(CadicalConfig::Plain);
+        assert!(builder.build(crate::load("./data/small.mcnf")).is_err());
+    }
+}
+
+#[cfg(feature = "minisat")]
+mod minisat {
+    type Oracle = rustsat_minisat::core::Minisat;
+
+    mod pmin {
+        type S = scuttle_core::PMinimal<super::Oracle>;
+        generate_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+
+    mod lb {
+        type S = scuttle_core::LowerBounding<super::Oracle>;
+        generate_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+
+    mod bioptsat {
+        type S = scuttle_core::BiOptSat<super::Oracle>;
+        generate_biobj_tests!(default, super::S, scuttle_core::KernelOptions::default());
+    }
+}
+
+mod callback {
+    use std::{cell::RefCell, rc::Rc};
+
+    use scuttle_core::{
+        KernelFunctions, Limits, MaybeTerminatedError, SolutionAction, Solve, Termination,
+    };
+
+    use crate::small_pmin;
+
+    #[test]
+    fn all_solutions() {
+        let mut solver = small_pmin();
+        let found = Rc::new(RefCell::new(vec![]));
+        let cb_found = found.clone();
+        solver.set_solution_callback(move |point, sol| {
+            assert!(point.iter().any(|s| s == sol));
+            cb_found.borrow_mut().push(point.costs().clone());
+            SolutionAction::Continue
+        });
+        solver.solve(Limits::none()).unwrap();
+        let pf = solver.pareto_front();
+        let found = found.borrow();
+        assert_eq!(found.len(), pf.len());
+        assert!(pf.into_iter().all(|pp| found.contains(pp.costs())));
+    }
+
+    #[test]
+    fn stop() {
+        let mut solver = small_pmin();
+        solver.set_solution_callback(|_, _| SolutionAction::Stop);
+        assert!(matches!(
+            solver.solve(Limits::none()),
+            MaybeTerminatedError::Terminated(Termination::Callback)
+        ));
+        assert_eq!(solver.pareto_front().len(), 1);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod trace {
+    use std::io::BufRead;
+
+    use scuttle_core::{
+        logging::{JsonlLogger, LogFilter},
+        KernelFunctions, Limits, Solve,
+    };
+
+    use crate::small_pmin;
+
+    fn events(trace: &tempfile::NamedTempFile) -> Vec<serde_json::Value> {
+        std::io::BufReader::new(trace.reopen().unwrap())
+            .lines()
+            .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn count(events: &[serde_json::Value], name: &str) -> usize {
+        events.iter().filter(|event| event["event"] == name).count()
+    }
+
+    #[test]
+    fn jsonl() {
+        let trace = tempfile::NamedTempFile::new().unwrap();
+        let mut solver = small_pmin();
+        let handle = solver.attach_logger(JsonlLogger::create(trace.path()).unwrap());
+        solver.solve(Limits::none()).unwrap();
+        // dropping the logger flushes the trace
+        drop(solver.detach_logger(handle));
+        let events = events(&trace);
+        assert!(events
+            .iter()
+            .all(|event| event["time"].is_f64() && event["cpu_time"].is_f64()));
+        assert_eq!(count(&events, "non-dominated"), 3);
+        assert!(count(&events, "candidate") >= 3);
+        assert!(count(&events, "oracle-call") > 0);
+        assert_eq!(
+            count(&events, "routine-start"),
+            count(&events, "routine-end")
+        );
+    }
+
+    #[test]
+    fn multiple_filtered() {
+        let full = tempfile::NamedTempFile::new().unwrap();
+        let filtered = tempfile::NamedTempFile::new().unwrap();
+        let mut solver = small_pmin();
+        let full_handle = solver.attach_logger(JsonlLogger::create(full.path()).unwrap());
+        let filtered_handle = solver.attach_filtered_logger(
+            JsonlLogger::create(filtered.path()).unwrap(),
+            LogFilter {
+                log_non_dom: true,
+                ..LogFilter::none()
+            },
+        );
+        solver.solve(Limits::none()).unwrap();
+        assert!(solver.detach_logger(filtered_handle).is_some());
+        assert!(solver.detach_logger(filtered_handle).is_none());
+        assert!(solver.detach_logger(full_handle).is_some());
+        let full = events(&full);
+        let filtered = events(&filtered);
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(count(&filtered, "non-dominated"), 3);
+        assert_eq!(count(&full, "non-dominated"), 3);
+        assert!(full.len() > filtered.len());
+    }
+}
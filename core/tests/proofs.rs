@@ -137,6 +137,40 @@ macro_rules! medium {
     };
 }
 
+macro_rules! medium_all {
+    ($s:ty, $o:expr) => {
+        test_instance!(
+            $s,
+            $o,
+            "./data/medium.mcnf",
+            vec![
+                (vec![0, 10], 1),
+                (vec![2, 8], 5),
+                (vec![4, 6], 10),
+                (vec![6, 4], 10),
+                (vec![8, 2], 5),
+                (vec![10, 0], 1),
+            ]
+        )
+    };
+    ($s:ty, $o:expr, $cbo:expr) => {
+        test_instance!(
+            $s,
+            $o,
+            $cbo,
+            "./data/medium.mcnf",
+            vec![
+                (vec![0, 10], 1),
+                (vec![2, 8], 5),
+                (vec![4, 6], 10),
+                (vec![6, 4], 10),
+                (vec![8, 2], 5),
+                (vec![10, 0], 1),
+            ]
+        )
+    };
+}
+
 macro_rules! medium_weighted {
     ($s:ty, $o:expr) => {
         test_instance!(
@@ -464,6 +498,21 @@ macro_rules! generate_tests {
                 medium!($s, $o);
             }
 
+            #[test]
+            fn medium_all_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::Solutions(None));
+                medium_all!($s, opts);
+            }
+
+            #[test]
+            fn medium_pmcs_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::PMCSs(None));
+                // every variable is an objective variable, so the P-MCSs are the solutions
+                medium_all!($s, opts);
+            }
+
             #[test]
             fn medium_weighted_cert() {
                 medium_weighted!($s, $o);
@@ -513,6 +562,13 @@ macro_rules! generate_tests {
                 medium!($s, $o, $cbo);
             }
 
+            #[test]
+            fn medium_all_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::Solutions(None));
+                medium_all!($s, opts, $cbo);
+            }
+
             #[test]
             fn medium_weighted_cert() {
                 medium_weighted!($s, $o, $cbo);
@@ -564,6 +620,21 @@ macro_rules! generate_biobj_tests {
                 medium!($s, $o);
             }
 
+            #[test]
+            fn medium_all_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::Solutions(None));
+                medium_all!($s, opts);
+            }
+
+            #[test]
+            fn medium_pmcs_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::PMCSs(None));
+                // every variable is an objective variable, so the P-MCSs are the solutions
+                medium_all!($s, opts);
+            }
+
             #[test]
             fn medium_weighted_cert() {
                 medium_weighted!($s, $o);
@@ -597,6 +668,13 @@ macro_rules! generate_biobj_tests {
                 medium!($s, $o, $cbo);
             }
 
+            #[test]
+            fn medium_all_cert() {
+                let mut opts = $o;
+                opts.set_enumeration(scuttle_core::options::EnumOptions::Solutions(None));
+                medium_all!($s, opts, $cbo);
+            }
+
             #[test]
             fn medium_weighted_cert() {
                 medium_weighted!($s, $o, $cbo);
@@ -701,3 +779,166 @@ mod bos {
         scuttle_core::CoreBoostingOptions::default()
     );
 }
+
+mod native_constraints {
+    use scuttle_core::{prepro, InitCertDefaultBlock, KernelFunctions, Solve};
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    /// Solves an OPB file with native constraints and checks the proof
+    /// against the file without its objectives
+    fn cert(inst_path: &str) -> usize {
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse_native(
+                inst_path,
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let vpb_input =
+            tempfile::NamedTempFile::new().expect("failed to create temporary proof file");
+        let (vpb_input, vpb_input_path) = vpb_input.into_parts();
+        let mut writer = std::io::BufWriter::new(vpb_input);
+        prepro::write_opb_veripb_input(inst_path, prepro::FileFormat::Infer, &mut writer).unwrap();
+        drop(writer);
+        crate::print_file(&vpb_input_path);
+        let (proof, path) = crate::new_proof(inst.n_constraints(), false);
+        let mut solver =
+            S::from_instance_default_blocking_cert(inst, Default::default(), proof).unwrap();
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        let pf = solver.pareto_front();
+        drop(solver); // ensure proof is concluded
+        crate::verify_proof(vpb_input_path, path);
+        pf.len()
+    }
+
+    #[test]
+    fn dal2_cert() {
+        assert_eq!(cert("./data/dal2.opb"), 10);
+    }
+
+    #[test]
+    fn mixed_cert() {
+        assert_eq!(cert("./data/mixed.opb"), 1);
+    }
+}
+
+mod conclusion {
+    use scuttle_core::{prepro, InitCertDefaultBlock, KernelFunctions, Solve};
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    #[test]
+    fn interrupted() {
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse(
+                "./data/medium.mcnf",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let vpb_input =
+            tempfile::NamedTempFile::new().expect("failed to create temporary proof file");
+        let (vpb_input, vpb_input_path) = vpb_input.into_parts();
+        let mut writer = std::io::BufWriter::new(vpb_input);
+        inst.write_veripb_input(&mut writer).unwrap();
+        drop(writer);
+        let (proof, path) = crate::new_proof(inst.n_constraints(), false);
+        let mut solver =
+            S::from_instance_default_blocking_cert(inst, Default::default(), proof).unwrap();
+        let res = solver.solve(scuttle_core::Limits {
+            pps: Some(2),
+            ..scuttle_core::Limits::none()
+        });
+        assert!(matches!(
+            res,
+            scuttle_core::MaybeTerminatedError::Terminated(scuttle_core::Termination::PPLimit)
+        ));
+        assert!(!solver.approximation().is_complete());
+        drop(solver); // ensure proof is concluded
+        crate::verify_proof(vpb_input_path, path);
+    }
+
+    #[test]
+    fn lexicographic() {
+        type L = scuttle_core::Lexicographic<rustsat_cadical::CaDiCaL<'static, 'static>>;
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse(
+                "./data/small.mcnf",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let vpb_input =
+            tempfile::NamedTempFile::new().expect("failed to create temporary proof file");
+        let (vpb_input, vpb_input_path) = vpb_input.into_parts();
+        let mut writer = std::io::BufWriter::new(vpb_input);
+        inst.write_veripb_input(&mut writer).unwrap();
+        drop(writer);
+        let (proof, path) = crate::new_proof(inst.n_constraints(), false);
+        let mut solver =
+            L::from_instance_default_blocking_cert(inst, Default::default(), proof).unwrap();
+        solver.solve(scuttle_core::Limits::none()).unwrap();
+        assert!(!solver.approximation().is_complete());
+        drop(solver); // ensure proof is concluded
+        crate::verify_proof(vpb_input_path, path);
+    }
+}
+
+mod encodings {
+    use scuttle_core::{prepro, InitCertDefaultBlock};
+
+    type S = scuttle_core::PMinimal<
+        rustsat_cadical::CaDiCaL<'static, 'static>,
+        rustsat::encodings::pb::DynamicPolyWatchdog,
+    >;
+
+    #[test]
+    fn unsupported() {
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse(
+                "./data/small.mcnf",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let (proof, _path) = crate::new_proof(inst.n_constraints(), false);
+        assert!(S::from_instance_default_blocking_cert(inst, Default::default(), proof).is_err());
+    }
+}
+
+mod inprocessing {
+    use scuttle_core::{
+        options::AfterCbOptions, prepro, CoreBoost, CoreBoostingOptions, InitCertDefaultBlock,
+        KernelOptions, MaybeTerminatedError,
+    };
+
+    type S = scuttle_core::PMinimal<rustsat_cadical::CaDiCaL<'static, 'static>>;
+
+    #[test]
+    fn unsupported() {
+        let inst = prepro::handle_soft_clauses(
+            prepro::parse(
+                "./data/small.mcnf",
+                prepro::FileFormat::Infer,
+                rustsat::instances::fio::opb::Options::default(),
+            )
+            .unwrap(),
+        );
+        let (proof, _path) = crate::new_proof(inst.n_constraints(), false);
+        let opts = KernelOptions {
+            store_cnf: true,
+            ..Default::default()
+        };
+        let mut solver = S::from_instance_default_blocking_cert(inst, opts, proof).unwrap();
+        let res = solver.core_boost(CoreBoostingOptions {
+            after: AfterCbOptions::Inpro(String::from("[[uvsrgc]VRTG]")),
+            ..Default::default()
+        });
+        assert!(matches!(res, MaybeTerminatedError::Error(_)));
+    }
+}
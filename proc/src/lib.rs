@@ -76,16 +76,42 @@ fn impl_kernel_functions_macro(mut ast: syn::DeriveInput, opts: KernelOpts) -> T
                 self.pareto_front.clone()
             }
 
+            fn approximation(&self) -> crate::types::ParetoApproximation {
+                #kernel.approximation()
+            }
+
             fn stats(&self) -> crate::Stats {
                 #kernel.stats
             }
 
-            fn attach_logger<L: crate::WriteSolverLog + 'static>(&mut self, logger: L) {
-                #kernel.attach_logger(logger)
+            fn attach_filtered_logger<L: crate::WriteSolverLog + 'static>(
+                &mut self,
+                logger: L,
+                filter: crate::logging::LogFilter,
+            ) -> crate::logging::LoggerHandle {
+                #kernel.attach_logger(logger, filter)
+            }
+
+            fn detach_logger(
+                &mut self,
+                handle: crate::logging::LoggerHandle,
+            ) -> Option<Box<dyn crate::WriteSolverLog>> {
+                #kernel.detach_logger(handle)
+            }
+
+            fn set_solution_callback<F>(&mut self, callback: F)
+            where
+                F: FnMut(
+                        &crate::types::NonDomPoint,
+                        &rustsat::types::Assignment,
+                    ) -> crate::algs::SolutionAction
+                    + 'static,
+            {
+                #kernel.set_solution_callback(callback)
             }
 
-            fn detach_logger(&mut self) -> Option<Box<dyn crate::WriteSolverLog>> {
-                #kernel.detach_logger()
+            fn clear_solution_callback(&mut self) {
+                #kernel.clear_solution_callback()
             }
 
             fn interrupter(&mut self) -> crate::algs::Interrupter {
@@ -1,11 +1,12 @@
 //! # Command Line Interface for the Solver Binary
 
+use std::cell::RefCell;
 use std::io::Error as IOError;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{
     fmt::{self},
-    io::Write,
+    io::{self, Write},
 };
 
 use clap::{crate_authors, crate_name, crate_version, Args, Parser, Subcommand, ValueEnum};
@@ -13,14 +14,18 @@ use cpu_time::ProcessTime;
 use rustsat::{
     instances::fio,
     solvers::{SolverResult, SolverStats},
+    types::Lit,
 };
+pub use scuttle_core::builder::{CadicalConfig, CardEncoding, OracleBackend, PbEncoding};
 use scuttle_core::prepro::FileFormat;
 use scuttle_core::{
+    logging::LogFilter,
     options::{
-        AfterCbOptions, CoreBoostingOptions, EnumOptions, HeurImprOptions, HeurImprWhen,
-        KernelOptions,
+        AfterCbOptions, BuildEncodings, CoreBoostingOptions, DivConAnchor, DivConOptions,
+        EnumOptions, HeurImprOptions, HeurImprWhen, KernelOptions, LexicographicOptions,
     },
-    types::{NonDomPoint, ParetoFront},
+    portfolio::PortfolioAlg,
+    types::{NonDomPoint, ParetoApproximation, ParetoFront},
     EncodingStats, Limits, Phase, Stats, Termination, WriteSolverLog,
 };
 use termcolor::{Buffer, BufferWriter, Color, ColorSpec, WriteColor};
@@ -49,6 +54,8 @@ enum AlgorithmCommand {
         #[command(flatten)]
         shared: SharedArgs,
         #[command(flatten)]
+        obj_encs: ObjEncArgs,
+        #[command(flatten)]
         cb: CoreBoostingArgs,
     },
     /// BiOptSat Linear Sat-Unsat - Jabs et al. SAT'22
@@ -65,11 +72,56 @@ enum AlgorithmCommand {
         #[command(flatten)]
         shared: SharedArgs,
         #[command(flatten)]
+        obj_encs: ObjEncArgs,
+        #[command(flatten)]
         cb: CoreBoostingArgs,
         /// Log fence updates
         #[arg(long)]
         log_fence: bool,
     },
+    /// Divide and conquer search over subsets of the objectives
+    DivCon {
+        #[command(flatten)]
+        shared: SharedArgs,
+        #[command(flatten)]
+        cb: CoreBoostingArgs,
+        /// The recursion anchor to use. One of `lin-su`, `bioptsat`, `p-minimal(<size>)`,
+        /// `lower-bounding(<size>)` or `n-minus(<x>)`, where `<size>` is either `+<n>` for an
+        /// absolute subproblem size or `-<n>` for a subproblem `n` smaller than the instance.
+        #[arg(long, default_value_t = DivConOptions::default().anchor)]
+        anchor: DivConAnchor,
+        /// When to build the objective encodings
+        #[arg(long, default_value_t = DivConOptions::default().build_encodings)]
+        build_encodings: BuildEncodings,
+    },
+    /// Lexicographic optimization with a priority order over the objectives
+    Lexicographic {
+        #[command(flatten)]
+        shared: SharedArgs,
+        #[command(flatten)]
+        cb: CoreBoostingArgs,
+        /// The objective indices in order of decreasing priority, separated by commas.
+        /// Objectives that are not listed are optimized afterwards in order of their index.
+        #[arg(long, value_delimiter = ',')]
+        priority: Vec<usize>,
+        /// Optimize each objective with core-guided search instead of linear sat-unsat search
+        #[arg(long, default_value_t = Bool::from(LexicographicOptions::default().core_guided))]
+        core_guided: Bool,
+    },
+    /// Portfolio of algorithms run in parallel until the first one finishes
+    Portfolio {
+        #[command(flatten)]
+        shared: SharedArgs,
+        #[command(flatten)]
+        cb: CoreBoostingArgs,
+        /// The algorithms to run in parallel, separated by commas. BiOptSat is skipped on
+        /// instances that are not bi-objective.
+        #[arg(long, value_delimiter = ',', default_values_t = [PortfolioAlg::PMinimal, PortfolioAlg::BiOptSat, PortfolioAlg::LowerBounding])]
+        algorithms: Vec<PortfolioAlg>,
+        /// Share non-dominated points between the p-minimal and lower-bounding runs
+        #[arg(long, default_value_t = Bool::False)]
+        cooperative: Bool,
+    },
 }
 
 #[derive(Args)]
@@ -92,9 +144,19 @@ struct SharedArgs {
     /// Whether to perform core exhaustion in OLL
     #[arg(long, default_value_t = Bool::from(KernelOptions::default().core_exhaustion))]
     core_exhaustion: Bool,
+    /// The SAT oracle to use. Proof logging is only supported with CaDiCaL.
+    #[arg(long, default_value_t = OracleBackend::Cadical)]
+    oracle: OracleBackend,
     /// The CaDiCaL profile to use
     #[arg(long, default_value_t = CadicalConfig::Default)]
     cadical_config: CadicalConfig,
+    /// A file with known solutions to seed the search with, e.g., from a heuristic or a previous
+    /// run on an older version of the instance. Either the JSON report of a run with
+    /// `--print-solutions`, or one solution per line starting with `v`, given as DIMACS literals.
+    /// The solutions are checked against the instance. Only supported for the p-minimal, BiOptSat
+    /// and lower-bounding algorithms, without preprocessing or reindexing.
+    #[arg(long)]
+    warm_start: Option<PathBuf>,
     #[command(flatten)]
     enumeration: EnumArgs,
     #[command(flatten)]
@@ -107,6 +169,8 @@ struct SharedArgs {
     log: LogArgs,
     #[command(flatten)]
     proof: ProofArgs,
+    #[command(flatten)]
+    checkpoint: CheckpointArgs,
 }
 
 #[derive(Args)]
@@ -114,7 +178,7 @@ struct ObjEncArgs {
     /// The encoding to use for weighted objectives
     #[arg(long, default_value_t = PbEncoding::default())]
     obj_pb_encoding: PbEncoding,
-    /// The encoding to use for unweighted objectivesh
+    /// The encoding to use for unweighted objectives
     #[arg(long, default_value_t = CardEncoding::default())]
     obj_card_encoding: CardEncoding,
 }
@@ -197,6 +261,18 @@ struct LimitArgs {
     /// Limit the number of SAT oracle calls (0 is not limit)
     #[arg(long, default_value_t = 0)]
     oracle_call_limit: usize,
+    /// Limit the wall-clock time in seconds (0 is no limit)
+    #[arg(long, default_value = "0", value_parser = parse_seconds)]
+    time_limit: Duration,
+    /// Limit the CPU time of the whole process in seconds (0 is no limit)
+    #[arg(long, default_value = "0", value_parser = parse_seconds)]
+    cpu_time_limit: Duration,
+}
+
+/// Parses a time limit given in seconds
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let secs: f64 = value.parse().map_err(|err| format!("{err}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| format!("{err}"))
 }
 
 impl From<&LimitArgs> for Limits {
@@ -206,6 +282,8 @@ impl From<&LimitArgs> for Limits {
             sols: none_if_zero!(value.sol_limit),
             candidates: none_if_zero!(value.candidate_limit),
             oracle_calls: none_if_zero!(value.oracle_call_limit),
+            time: (!value.time_limit.is_zero()).then_some(value.time_limit),
+            cpu_time: (!value.cpu_time_limit.is_zero()).then_some(value.cpu_time_limit),
         }
     }
 }
@@ -219,6 +297,14 @@ struct FileArgs {
     /// The index in the OPB file to treat as the lowest variable
     #[arg(long, default_value_t = 1)]
     first_var_idx: u32,
+    /// Keep cardinality and pseudo-Boolean constraints as native constraints
+    /// rather than encoding them to CNF when parsing. The constraints are
+    /// encoded when initializing the solver, with certified encodings when
+    /// proof logging, and are written to the VeriPB input as they are. This
+    /// is always enabled when proof logging. Preprocessing and reindexing
+    /// encode the constraints to CNF beforehand.
+    #[arg(long, default_value_t = Bool::False)]
+    native_constraints: Bool,
     /// The path to the instance file to load. Compressed files with an
     /// extension like `.bz2` or `.gz` can be read.
     inst_path: PathBuf,
@@ -237,6 +323,13 @@ struct LogArgs {
     /// Don't print statistics
     #[arg(long)]
     no_print_stats: bool,
+    /// The format of the final output. With `json` or `csv`, a machine-readable report is written
+    /// to stdout at the end of solving and all other output is written to stderr.
+    #[arg(long, default_value_t = OutputFormat::Text)]
+    output_format: OutputFormat,
+    /// Write a trace of all solver events to this file, one JSON object per line
+    #[arg(long)]
+    trace_file: Option<PathBuf>,
     /// Verbosity of the solver output
     #[arg(short, long, default_value_t = 0)]
     verbosity: u8,
@@ -273,19 +366,53 @@ struct LogArgs {
 #[derive(Args)]
 struct ProofArgs {
     /// The path to write the VeriPB proof to. If not provided, will not write a proof.
+    ///
+    /// Proofs written to paths with a `.gz`, `.xz` or `.zst` extension are compressed on the fly.
+    /// With `-`, the proof is written to stdout and all other output to stderr. A named pipe can be
+    /// given as the path to let a checker consume the proof while solving.
+    ///
+    /// The proof concludes unsatisfiability if the entire Pareto front was found and makes no claim
+    /// otherwise. VeriPB cannot express a Pareto front in the conclusion, so the non-dominated
+    /// points, and the feasible and lower bound points of a partial front, are only written to the
+    /// proof as comments.
+    ///
+    /// With enumeration, every enumerated solution is logged in the proof. Without an enumeration
+    /// limit, the concluded unsatisfiability then certifies that all solutions at each
+    /// non-dominated point were enumerated.
     proof_path: Option<PathBuf>,
     /// The path to output the VeriPB input to. If not provided, will write to
     /// `scuttle-veripb-input.opb`.
     ///
     /// VeriPB does not natively understand multi-objective input files, so Scuttle will write only
     /// the constraints to a separate OPB file for VeriPB to use as input, while the objectives are
-    /// written to the proof as an order.
+    /// written to the proof as an order. For OPB inputs, the VeriPB input is the input file without
+    /// its objectives, and the encoding of its constraints to CNF is certified in the proof. With
+    /// reindexing, the reindexed clauses are written instead.
     veripb_input_path: Option<PathBuf>,
+    /// After solving, check the proof with a locally installed `veripb` and fail if the proof is
+    /// rejected
+    #[arg(long)]
+    check_proof: bool,
+}
+
+#[derive(Args)]
+struct CheckpointArgs {
+    /// The path to write a checkpoint of the solving progress to when solving ends, including when
+    /// it is terminated early, e.g., by a limit or an interrupt. Only supported for the
+    /// p-minimal, BiOptSat and lower-bounding algorithms.
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+    /// The path of a checkpoint to resume solving from. The checkpoint must have been written for
+    /// the same instance, algorithm and preprocessing options. Core boosting is not run again when
+    /// resuming, since its lower bounds are part of the checkpoint. Not supported with proof
+    /// logging.
+    #[arg(long)]
+    resume: Option<PathBuf>,
 }
 
-impl From<&LogArgs> for LoggerConfig {
+impl From<&LogArgs> for LogFilter {
     fn from(value: &LogArgs) -> Self {
-        LoggerConfig {
+        LogFilter {
             log_candidates: value.log_candidates || value.verbosity >= 2,
             log_solutions: value.log_solutions,
             log_non_dom: value.log_non_dom || value.verbosity >= 1,
@@ -298,39 +425,7 @@ impl From<&LogArgs> for LoggerConfig {
             log_bound_points: value.log_bound_points || value.verbosity >= 2,
             log_cores: value.log_cores || value.verbosity >= 2,
             log_inpro: value.log_inprocessing || value.verbosity >= 1,
-        }
-    }
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
-pub enum PbEncoding {
-    /// Generalized totalizer encoding - Joshi et al. CP'15
-    #[default]
-    Gte,
-    // /// Dynamic polynomial watchdog encoding - Paxian et al. SAT'18
-    // Dpw,
-}
-
-impl fmt::Display for PbEncoding {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            PbEncoding::Gte => write!(f, "gte"),
-            // PbEncoding::Dpw => write!(f, "dpw"),
-        }
-    }
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
-pub enum CardEncoding {
-    /// Totalizer encoding - Ballieux and Boufkhad CP'03
-    #[default]
-    Tot,
-}
-
-impl fmt::Display for CardEncoding {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            CardEncoding::Tot => write!(f, "tot"),
+            log_messages: true,
         }
     }
 }
@@ -368,40 +463,6 @@ impl From<bool> for Bool {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
-pub enum CadicalConfig {
-    /// Set default advanced internal options
-    Default,
-    /// Disable all internal preprocessing options
-    Plain,
-    /// Set internal options to target satisfiable instances
-    Sat,
-    /// Set internal options to target unsatisfiable instances
-    Unsat,
-}
-
-impl fmt::Display for CadicalConfig {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            CadicalConfig::Default => write!(f, "default"),
-            CadicalConfig::Plain => write!(f, "plain"),
-            CadicalConfig::Sat => write!(f, "sat"),
-            CadicalConfig::Unsat => write!(f, "unsat"),
-        }
-    }
-}
-
-impl From<CadicalConfig> for rustsat_cadical::Config {
-    fn from(cfg: CadicalConfig) -> Self {
-        match cfg {
-            CadicalConfig::Default => rustsat_cadical::Config::Default,
-            CadicalConfig::Plain => rustsat_cadical::Config::Plain,
-            CadicalConfig::Sat => rustsat_cadical::Config::Sat,
-            CadicalConfig::Unsat => rustsat_cadical::Config::Unsat,
-        }
-    }
-}
-
 #[derive(Default, Copy, Clone, PartialEq, Eq, ValueEnum)]
 pub enum EnumOptionsArg {
     #[default]
@@ -424,15 +485,38 @@ impl fmt::Display for EnumOptionsArg {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
+pub enum OutputFormat {
+    /// Human-readable text blocks
+    #[default]
+    Text,
+    /// A single JSON document
+    Json,
+    /// Comma-separated `key,value` rows
+    Csv,
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
 pub struct Cli {
     pub limits: Limits,
     pub file_format: FileFormat,
     pub opb_options: fio::opb::Options,
+    pub native_constraints: bool,
     pub inst_path: PathBuf,
     pub preprocessing: bool,
     pub maxpre_techniques: String,
     pub reindexing: bool,
     pub maxpre_reindexing: bool,
+    pub oracle: OracleBackend,
     pub cadical_config: CadicalConfig,
     stdout: BufferWriter,
     stderr: BufferWriter,
@@ -440,20 +524,49 @@ pub struct Cli {
     print_solutions: bool,
     print_stats: bool,
     color: concolor_clap::Color,
-    logger_config: LoggerConfig,
+    output_format: OutputFormat,
+    report: RefCell<Report>,
+    logger_config: LogFilter,
+    pub trace_path: Option<PathBuf>,
     pub alg: Algorithm,
     pub proof_paths: Option<(PathBuf, PathBuf)>,
+    pub check_proof: bool,
+    pub checkpoint_path: Option<PathBuf>,
+    pub resume_path: Option<PathBuf>,
+    pub warm_start_path: Option<PathBuf>,
 }
 
 pub enum Algorithm {
-    PMinimal(KernelOptions, Option<CoreBoostingOptions>),
+    PMinimal(
+        KernelOptions,
+        PbEncoding,
+        CardEncoding,
+        Option<CoreBoostingOptions>,
+    ),
     BiOptSat(
         KernelOptions,
         PbEncoding,
         CardEncoding,
         Option<CoreBoostingOptions>,
     ),
-    LowerBounding(KernelOptions, Option<CoreBoostingOptions>),
+    LowerBounding(
+        KernelOptions,
+        PbEncoding,
+        CardEncoding,
+        Option<CoreBoostingOptions>,
+    ),
+    DivCon(KernelOptions, DivConOptions, Option<CoreBoostingOptions>),
+    Lexicographic(
+        KernelOptions,
+        LexicographicOptions,
+        Option<CoreBoostingOptions>,
+    ),
+    Portfolio(
+        KernelOptions,
+        Vec<PortfolioAlg>,
+        bool,
+        Option<CoreBoostingOptions>,
+    ),
 }
 
 impl fmt::Display for Algorithm {
@@ -462,18 +575,21 @@ impl fmt::Display for Algorithm {
             Algorithm::PMinimal(..) => write!(f, "p-pminimal"),
             Algorithm::BiOptSat(..) => write!(f, "bioptsat"),
             Algorithm::LowerBounding(..) => write!(f, "lower-bounding"),
+            Algorithm::DivCon(..) => write!(f, "div-con"),
+            Algorithm::Lexicographic(..) => write!(f, "lexicographic"),
+            Algorithm::Portfolio(..) => write!(f, "portfolio"),
         }
     }
 }
 
 impl Cli {
     pub fn init() -> Self {
-        let stdout = |color: concolor_clap::Color| {
-            BufferWriter::stdout(match color.color {
+        let stderr = |color: concolor_clap::Color| {
+            BufferWriter::stderr(match color.color {
                 concolor_clap::ColorChoice::Always => termcolor::ColorChoice::Always,
                 concolor_clap::ColorChoice::Never => termcolor::ColorChoice::Never,
                 concolor_clap::ColorChoice::Auto => {
-                    if atty::is(atty::Stream::Stdout) {
+                    if atty::is(atty::Stream::Stderr) {
                         termcolor::ColorChoice::Auto
                     } else {
                         termcolor::ColorChoice::Never
@@ -481,12 +597,19 @@ impl Cli {
                 }
             })
         };
-        let stderr = |color: concolor_clap::Color| {
-            BufferWriter::stderr(match color.color {
+        // with a machine-readable output format or the proof written to
+        // stdout, stdout is reserved for the report or proof
+        let stdout = |log: &LogArgs, proof: &ProofArgs| {
+            if log.output_format != OutputFormat::Text
+                || proof.proof_path.as_deref() == Some(Path::new("-"))
+            {
+                return stderr(log.color);
+            }
+            BufferWriter::stdout(match log.color.color {
                 concolor_clap::ColorChoice::Always => termcolor::ColorChoice::Always,
                 concolor_clap::ColorChoice::Never => termcolor::ColorChoice::Never,
                 concolor_clap::ColorChoice::Auto => {
-                    if atty::is(atty::Stream::Stderr) {
+                    if atty::is(atty::Stream::Stdout) {
                         termcolor::ColorChoice::Auto
                     } else {
                         termcolor::ColorChoice::Never
@@ -527,7 +650,11 @@ impl Cli {
             })
         };
         match CliArgs::parse().command {
-            AlgorithmCommand::PMinimal { shared, cb } => {
+            AlgorithmCommand::PMinimal {
+                shared,
+                obj_encs,
+                cb,
+            } => {
                 let (cb, store_cnf) = cb.parse(shared.prepro.maxpre_techniques.clone());
                 let proof_paths = proof_paths(&shared);
                 Cli {
@@ -537,20 +664,34 @@ impl Cli {
                         first_var_idx: shared.file.first_var_idx,
                         ..Default::default()
                     },
+                    native_constraints: shared.file.native_constraints.into(),
                     inst_path: shared.file.inst_path.clone(),
                     preprocessing: shared.prepro.preprocessing.into(),
                     maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
                     reindexing: shared.prepro.reindexing.into(),
                     maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
                     cadical_config: shared.cadical_config.into(),
-                    stdout: stdout(shared.log.color),
+                    stdout: stdout(&shared.log, &shared.proof),
                     stderr: stderr(shared.log.color),
                     print_solver_config: shared.log.print_solver_config,
                     print_solutions: shared.log.print_solutions,
                     print_stats: !shared.log.no_print_stats,
                     color: shared.log.color,
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
                     logger_config: (&shared.log).into(),
-                    alg: Algorithm::PMinimal(kernel_opts(shared, store_cnf), cb),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
+                    alg: Algorithm::PMinimal(
+                        kernel_opts(shared, store_cnf),
+                        obj_encs.obj_pb_encoding,
+                        obj_encs.obj_card_encoding,
+                        cb,
+                    ),
                     proof_paths,
                 }
             }
@@ -568,19 +709,28 @@ impl Cli {
                         first_var_idx: shared.file.first_var_idx,
                         ..Default::default()
                     },
+                    native_constraints: shared.file.native_constraints.into(),
                     inst_path: shared.file.inst_path.clone(),
                     preprocessing: shared.prepro.preprocessing.into(),
                     maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
                     reindexing: shared.prepro.reindexing.into(),
                     maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
                     cadical_config: shared.cadical_config.into(),
-                    stdout: stdout(shared.log.color),
+                    stdout: stdout(&shared.log, &shared.proof),
                     stderr: stderr(shared.log.color),
                     print_solver_config: shared.log.print_solver_config,
                     print_solutions: shared.log.print_solutions,
                     print_stats: !shared.log.no_print_stats,
                     color: shared.log.color,
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
                     logger_config: (&shared.log).into(),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
                     alg: Algorithm::BiOptSat(
                         kernel_opts(shared, store_cnf),
                         obj_encs.obj_pb_encoding,
@@ -592,6 +742,7 @@ impl Cli {
             }
             AlgorithmCommand::LowerBounding {
                 shared,
+                obj_encs,
                 log_fence,
                 cb,
             } => {
@@ -604,23 +755,180 @@ impl Cli {
                         first_var_idx: shared.file.first_var_idx,
                         ..Default::default()
                     },
+                    native_constraints: shared.file.native_constraints.into(),
                     inst_path: shared.file.inst_path.clone(),
                     preprocessing: shared.prepro.preprocessing.into(),
                     maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
                     reindexing: shared.prepro.reindexing.into(),
                     maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
                     cadical_config: shared.cadical_config.into(),
-                    stdout: stdout(shared.log.color),
+                    stdout: stdout(&shared.log, &shared.proof),
                     stderr: stderr(shared.log.color),
                     print_solver_config: shared.log.print_solver_config,
                     print_solutions: shared.log.print_solutions,
                     print_stats: !shared.log.no_print_stats,
                     color: shared.log.color,
-                    logger_config: LoggerConfig {
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
+                    logger_config: LogFilter {
                         log_fence: log_fence || shared.log.verbosity >= 2,
                         ..(&shared.log).into()
                     },
-                    alg: Algorithm::LowerBounding(kernel_opts(shared, store_cnf), cb),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
+                    alg: Algorithm::LowerBounding(
+                        kernel_opts(shared, store_cnf),
+                        obj_encs.obj_pb_encoding,
+                        obj_encs.obj_card_encoding,
+                        cb,
+                    ),
+                    proof_paths,
+                }
+            }
+            AlgorithmCommand::DivCon {
+                shared,
+                cb,
+                anchor,
+                build_encodings,
+            } => {
+                let (cb, store_cnf) = cb.parse(shared.prepro.maxpre_techniques.clone());
+                let store_cnf = store_cnf || build_encodings == BuildEncodings::CleanRebuild;
+                let proof_paths = proof_paths(&shared);
+                Cli {
+                    limits: (&shared.limits).into(),
+                    file_format: shared.file.file_format,
+                    opb_options: fio::opb::Options {
+                        first_var_idx: shared.file.first_var_idx,
+                        ..Default::default()
+                    },
+                    native_constraints: shared.file.native_constraints.into(),
+                    inst_path: shared.file.inst_path.clone(),
+                    preprocessing: shared.prepro.preprocessing.into(),
+                    maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
+                    reindexing: shared.prepro.reindexing.into(),
+                    maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
+                    cadical_config: shared.cadical_config.into(),
+                    stdout: stdout(&shared.log, &shared.proof),
+                    stderr: stderr(shared.log.color),
+                    print_solver_config: shared.log.print_solver_config,
+                    print_solutions: shared.log.print_solutions,
+                    print_stats: !shared.log.no_print_stats,
+                    color: shared.log.color,
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
+                    logger_config: (&shared.log).into(),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
+                    alg: Algorithm::DivCon(
+                        kernel_opts(shared, store_cnf),
+                        DivConOptions {
+                            anchor,
+                            build_encodings,
+                        },
+                        cb,
+                    ),
+                    proof_paths,
+                }
+            }
+            AlgorithmCommand::Lexicographic {
+                shared,
+                cb,
+                priority,
+                core_guided,
+            } => {
+                let (cb, store_cnf) = cb.parse(shared.prepro.maxpre_techniques.clone());
+                let proof_paths = proof_paths(&shared);
+                Cli {
+                    limits: (&shared.limits).into(),
+                    file_format: shared.file.file_format,
+                    opb_options: fio::opb::Options {
+                        first_var_idx: shared.file.first_var_idx,
+                        ..Default::default()
+                    },
+                    native_constraints: shared.file.native_constraints.into(),
+                    inst_path: shared.file.inst_path.clone(),
+                    preprocessing: shared.prepro.preprocessing.into(),
+                    maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
+                    reindexing: shared.prepro.reindexing.into(),
+                    maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
+                    cadical_config: shared.cadical_config.into(),
+                    stdout: stdout(&shared.log, &shared.proof),
+                    stderr: stderr(shared.log.color),
+                    print_solver_config: shared.log.print_solver_config,
+                    print_solutions: shared.log.print_solutions,
+                    print_stats: !shared.log.no_print_stats,
+                    color: shared.log.color,
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
+                    logger_config: (&shared.log).into(),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
+                    alg: Algorithm::Lexicographic(
+                        kernel_opts(shared, store_cnf),
+                        LexicographicOptions {
+                            priority,
+                            core_guided: core_guided.into(),
+                        },
+                        cb,
+                    ),
+                    proof_paths,
+                }
+            }
+            AlgorithmCommand::Portfolio {
+                shared,
+                cb,
+                algorithms,
+                cooperative,
+            } => {
+                let (cb, store_cnf) = cb.parse(shared.prepro.maxpre_techniques.clone());
+                let proof_paths = proof_paths(&shared);
+                Cli {
+                    limits: (&shared.limits).into(),
+                    file_format: shared.file.file_format,
+                    opb_options: fio::opb::Options {
+                        first_var_idx: shared.file.first_var_idx,
+                        ..Default::default()
+                    },
+                    native_constraints: shared.file.native_constraints.into(),
+                    inst_path: shared.file.inst_path.clone(),
+                    preprocessing: shared.prepro.preprocessing.into(),
+                    maxpre_techniques: shared.prepro.maxpre_techniques.clone(),
+                    reindexing: shared.prepro.reindexing.into(),
+                    maxpre_reindexing: shared.prepro.maxpre_reindexing.into(),
+                    oracle: shared.oracle,
+                    cadical_config: shared.cadical_config.into(),
+                    stdout: stdout(&shared.log, &shared.proof),
+                    stderr: stderr(shared.log.color),
+                    print_solver_config: shared.log.print_solver_config,
+                    print_solutions: shared.log.print_solutions,
+                    print_stats: !shared.log.no_print_stats,
+                    color: shared.log.color,
+                    output_format: shared.log.output_format,
+                    report: RefCell::default(),
+                    logger_config: (&shared.log).into(),
+                    trace_path: shared.log.trace_file.clone(),
+                    check_proof: shared.proof.check_proof,
+                    checkpoint_path: shared.checkpoint.checkpoint.clone(),
+                    resume_path: shared.checkpoint.resume.clone(),
+                    warm_start_path: shared.warm_start.clone(),
+                    alg: Algorithm::Portfolio(
+                        kernel_opts(shared, store_cnf),
+                        algorithms,
+                        cooperative.into(),
+                        cb,
+                    ),
                     proof_paths,
                 }
             }
@@ -628,6 +936,23 @@ impl Cli {
     }
 
     pub fn new_cli_logger(&self) -> CliLogger {
+        if self.machine_readable_output() || self.proof_to_stdout() {
+            return CliLogger {
+                stdout: BufferWriter::stderr(match self.color.color {
+                    concolor_clap::ColorChoice::Always => termcolor::ColorChoice::Always,
+                    concolor_clap::ColorChoice::Never => termcolor::ColorChoice::Never,
+                    concolor_clap::ColorChoice::Auto => {
+                        if atty::is(atty::Stream::Stderr) {
+                            termcolor::ColorChoice::Auto
+                        } else {
+                            termcolor::ColorChoice::Never
+                        }
+                    }
+                }),
+                config: self.logger_config.clone(),
+                routine_stack: vec![],
+            };
+        }
         CliLogger {
             stdout: BufferWriter::stdout(match self.color.color {
                 concolor_clap::ColorChoice::Always => termcolor::ColorChoice::Always,
@@ -645,6 +970,18 @@ impl Cli {
         }
     }
 
+    /// Checks whether a machine-readable report is written to stdout
+    pub fn machine_readable_output(&self) -> bool {
+        self.output_format != OutputFormat::Text
+    }
+
+    /// Checks whether the proof is written to stdout
+    pub fn proof_to_stdout(&self) -> bool {
+        self.proof_paths
+            .as_ref()
+            .is_some_and(|(proof_path, _)| proof_path == Path::new("-"))
+    }
+
     pub fn warning(&self, msg: &str) -> Result<(), IOError> {
         let mut buffer = self.stderr.buffer();
         buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Yellow)))?;
@@ -685,6 +1022,7 @@ impl Cli {
     }
 
     pub fn log_termination(&self, term: &Termination) -> Result<(), IOError> {
+        self.report.borrow_mut().termination = Some(*term);
         let msg = &format!("{}", term);
         self.warning(msg)
     }
@@ -721,13 +1059,16 @@ impl Cli {
             writeln!(buffer, ": ")?;
             buffer.reset()?;
             match &self.alg {
-                Algorithm::PMinimal(opts, cb_opts) | Algorithm::LowerBounding(opts, cb_opts) => {
+                Algorithm::PMinimal(opts, pb_enc, card_enc, cb_opts)
+                | Algorithm::LowerBounding(opts, pb_enc, card_enc, cb_opts) => {
                     Self::print_parameter(
                         &mut buffer,
                         "enumeration",
                         EnumPrinter::new(opts.enumeration),
                     )?;
                     Self::print_parameter(&mut buffer, "reserve-enc-vars", opts.reserve_enc_vars)?;
+                    Self::print_parameter(&mut buffer, "obj-pb-encoding", pb_enc)?;
+                    Self::print_parameter(&mut buffer, "obj-card-encoding", card_enc)?;
                     Self::print_parameter(&mut buffer, "core-boosting", cb_opts.is_some())?;
                 }
                 Algorithm::BiOptSat(opts, pb_enc, card_enc, cb_opts) => {
@@ -741,7 +1082,47 @@ impl Cli {
                     Self::print_parameter(&mut buffer, "obj-card-encoding", card_enc)?;
                     Self::print_parameter(&mut buffer, "core-boosting", cb_opts.is_some())?;
                 }
+                Algorithm::DivCon(opts, dc_opts, cb_opts) => {
+                    Self::print_parameter(
+                        &mut buffer,
+                        "enumeration",
+                        EnumPrinter::new(opts.enumeration),
+                    )?;
+                    Self::print_parameter(&mut buffer, "reserve-enc-vars", opts.reserve_enc_vars)?;
+                    Self::print_parameter(&mut buffer, "anchor", dc_opts.anchor)?;
+                    Self::print_parameter(&mut buffer, "build-encodings", dc_opts.build_encodings)?;
+                    Self::print_parameter(&mut buffer, "core-boosting", cb_opts.is_some())?;
+                }
+                Algorithm::Lexicographic(opts, lex_opts, cb_opts) => {
+                    Self::print_parameter(
+                        &mut buffer,
+                        "enumeration",
+                        EnumPrinter::new(opts.enumeration),
+                    )?;
+                    Self::print_parameter(&mut buffer, "reserve-enc-vars", opts.reserve_enc_vars)?;
+                    Self::print_parameter(
+                        &mut buffer,
+                        "priority",
+                        VecPrinter::new(&lex_opts.priority),
+                    )?;
+                    Self::print_parameter(&mut buffer, "core-guided", lex_opts.core_guided)?;
+                    Self::print_parameter(&mut buffer, "core-boosting", cb_opts.is_some())?;
+                }
+                Algorithm::Portfolio(opts, algs, cooperative, cb_opts) => {
+                    Self::print_parameter(
+                        &mut buffer,
+                        "enumeration",
+                        EnumPrinter::new(opts.enumeration),
+                    )?;
+                    Self::print_parameter(&mut buffer, "reserve-enc-vars", opts.reserve_enc_vars)?;
+                    Self::print_parameter(&mut buffer, "algorithms", VecPrinter::new(algs))?;
+                    Self::print_parameter(&mut buffer, "cooperative", cooperative)?;
+                    Self::print_parameter(&mut buffer, "core-boosting", cb_opts.is_some())?;
+                }
             }
+            Self::print_parameter(&mut buffer, "oracle", self.oracle)?;
+            Self::print_parameter(&mut buffer, "native-constraints", self.native_constraints)?;
+            Self::print_parameter(&mut buffer, "check-proof", self.check_proof)?;
             Self::print_parameter(&mut buffer, "pp-limit", OptVal::new(self.limits.pps))?;
             Self::print_parameter(&mut buffer, "sol-limit", OptVal::new(self.limits.sols))?;
             Self::print_parameter(
@@ -754,16 +1135,38 @@ impl Cli {
                 "oracle-call-limit",
                 OptVal::new(self.limits.oracle_calls),
             )?;
+            Self::print_parameter(
+                &mut buffer,
+                "time-limit",
+                OptVal::new(self.limits.time.map(DurPrinter::new)),
+            )?;
+            Self::print_parameter(
+                &mut buffer,
+                "cpu-time-limit",
+                OptVal::new(self.limits.cpu_time.map(DurPrinter::new)),
+            )?;
             Self::end_block(&mut buffer)?;
             self.stdout.print(&buffer)?;
         }
         Ok(())
     }
 
-    pub fn print_pareto_front<S: Clone + Eq + fmt::Display>(
-        &self,
-        pareto_front: ParetoFront<S>,
-    ) -> Result<(), IOError> {
+    pub fn print_pareto_front(&self, pareto_front: ParetoFront) -> Result<(), IOError> {
+        if self.output_format != OutputFormat::Text {
+            self.report.borrow_mut().pareto_front = pareto_front
+                .into_iter()
+                .map(|pp| PointReport {
+                    costs: pp.costs().clone(),
+                    n_sols: pp.n_sols(),
+                    solutions: self.print_solutions.then(|| {
+                        pp.into_iter()
+                            .map(|sol| sol.into_iter().map(Lit::to_ipasir).collect())
+                            .collect()
+                    }),
+                })
+                .collect();
+            return Ok(());
+        }
         let mut buffer = self.stdout.buffer();
         Self::start_block(&mut buffer)?;
         buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
@@ -779,8 +1182,43 @@ impl Cli {
         Ok(())
     }
 
+    pub fn print_approximation(&self, approx: &ParetoApproximation) -> Result<(), IOError> {
+        if approx.is_complete() {
+            return Ok(());
+        }
+        if self.output_format != OutputFormat::Text {
+            let mut report = self.report.borrow_mut();
+            report.epsilon_gap = approx.epsilon_gap();
+            report.approximation = Some(approx.clone());
+            return Ok(());
+        }
+        let mut buffer = self.stdout.buffer();
+        Self::start_block(&mut buffer)?;
+        buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
+        write!(buffer, "Pareto Front Approximation")?;
+        buffer.set_color(ColorSpec::new().set_bold(true))?;
+        writeln!(buffer, ": ")?;
+        buffer.reset()?;
+        approx.feasible().iter().try_fold((), |_, costs| {
+            Self::print_parameter(&mut buffer, "feasible-point", VecPrinter::new(costs))
+        })?;
+        approx.lower_bounds().iter().try_fold((), |_, costs| {
+            Self::print_parameter(&mut buffer, "lower-bound-point", VecPrinter::new(costs))
+        })?;
+        Self::print_parameter(
+            &mut buffer,
+            "epsilon-gap",
+            OptVal::new(approx.epsilon_gap()),
+        )?;
+        Self::end_block(&mut buffer)?;
+        self.stdout.print(&buffer)?;
+        Ok(())
+    }
+
     pub fn print_stats(&self, stats: Stats) -> Result<(), IOError> {
-        if self.print_stats {
+        if self.print_stats && self.output_format != OutputFormat::Text {
+            self.report.borrow_mut().stats = Some(stats);
+        } else if self.print_stats {
             let mut buffer = self.stdout.buffer();
             Self::start_block(&mut buffer)?;
             buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
@@ -802,7 +1240,16 @@ impl Cli {
     }
 
     pub fn print_oracle_stats(&self, stats: SolverStats) -> Result<(), IOError> {
-        if self.print_stats {
+        if self.print_stats && self.output_format != OutputFormat::Text {
+            self.report.borrow_mut().oracle_stats = Some(serde_json::json!({
+                "n_sat_solves": stats.n_sat,
+                "n_unsat_solves": stats.n_unsat,
+                "n_clauses": stats.n_clauses,
+                "max_var": stats.max_var.map(|var| var.pos_lit().to_ipasir()),
+                "avg_clause_len": stats.avg_clause_len,
+                "cpu_solve_time": stats.cpu_solve_time.as_secs_f64(),
+            }));
+        } else if self.print_stats {
             let mut buffer = self.stdout.buffer();
             Self::start_block(&mut buffer)?;
             buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
@@ -828,7 +1275,9 @@ impl Cli {
     }
 
     pub fn print_encoding_stats(&self, stats: Vec<EncodingStats>) -> Result<(), IOError> {
-        if self.print_stats {
+        if self.print_stats && self.output_format != OutputFormat::Text {
+            self.report.borrow_mut().encoding_stats = Some(stats);
+        } else if self.print_stats {
             let mut buffer = self.stdout.buffer();
             Self::start_block(&mut buffer)?;
             buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
@@ -850,7 +1299,20 @@ impl Cli {
     }
 
     pub fn print_maxpre_stats(&self, stats: maxpre::Stats) -> Result<(), IOError> {
-        if self.print_stats {
+        if self.print_stats && self.output_format != OutputFormat::Text {
+            self.report.borrow_mut().maxpre_stats = Some(serde_json::json!({
+                "n_objs": stats.n_objs,
+                "n_orig_hard_clauses": stats.n_orig_hard_clauses,
+                "n_orig_soft_clauses": stats.n_orig_soft_clauses,
+                "max_orig_var": stats.max_orig_var.map(|var| var.pos_lit().to_ipasir()),
+                "n_prepro_hard_clauses": stats.n_prepro_hard_clauses,
+                "n_prepro_soft_clauses": stats.n_prepro_soft_clauses,
+                "max_prepro_var": stats.max_prepro_var.map(|var| var.pos_lit().to_ipasir()),
+                "removed_weight": stats.removed_weight,
+                "prepro_time": stats.prepro_time.as_secs_f64(),
+                "reconst_time": stats.reconst_time.as_secs_f64(),
+            }));
+        } else if self.print_stats {
             let mut buffer = self.stdout.buffer();
             Self::start_block(&mut buffer)?;
             buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
@@ -907,6 +1369,61 @@ impl Cli {
         Ok(())
     }
 
+    pub fn print_proof_check(&self, accepted: bool, check_time: Duration) -> Result<(), IOError> {
+        self.report.borrow_mut().proof_check = Some(ProofCheckReport {
+            accepted,
+            check_time: check_time.as_secs_f64(),
+        });
+        if self.output_format != OutputFormat::Text {
+            return Ok(());
+        }
+        let mut buffer = self.stdout.buffer();
+        Self::start_block(&mut buffer)?;
+        buffer.set_color(ColorSpec::new().set_bold(true).set_fg(Some(Color::Blue)))?;
+        write!(buffer, "Proof Check")?;
+        buffer.reset()?;
+        buffer.set_color(ColorSpec::new().set_bold(true))?;
+        writeln!(buffer, ": ")?;
+        buffer.reset()?;
+        Self::print_parameter(
+            &mut buffer,
+            "verdict",
+            if accepted { "accepted" } else { "rejected" },
+        )?;
+        Self::print_parameter(&mut buffer, "check-time", DurPrinter::new(check_time))?;
+        Self::end_block(&mut buffer)?;
+        self.stdout.print(&buffer)?;
+        Ok(())
+    }
+
+    /// Checks whether the proof was checked and rejected
+    pub fn proof_rejected(&self) -> bool {
+        self.report
+            .borrow()
+            .proof_check
+            .as_ref()
+            .is_some_and(|check| !check.accepted)
+    }
+
+    /// Writes the machine-readable report to stdout, if a machine-readable
+    /// output format was selected
+    pub fn print_report(&self) -> Result<(), IOError> {
+        let report = self.report.borrow();
+        let mut stdout = io::stdout().lock();
+        match self.output_format {
+            OutputFormat::Text => return Ok(()),
+            OutputFormat::Json => {
+                serde_json::to_writer_pretty(&mut stdout, &*report)?;
+                writeln!(stdout)?;
+            }
+            OutputFormat::Csv => {
+                writeln!(stdout, "key,value")?;
+                write_csv_rows(&mut stdout, "", &serde_json::to_value(&*report)?)?;
+            }
+        }
+        stdout.flush()
+    }
+
     fn print_non_dom<S: Clone + Eq + fmt::Display>(
         &self,
         buffer: &mut Buffer,
@@ -978,24 +1495,88 @@ impl Cli {
     }
 }
 
-#[derive(Clone)]
-struct LoggerConfig {
-    log_candidates: bool,
-    log_solutions: bool,
-    log_non_dom: bool,
-    log_oracle_calls: bool,
-    #[cfg(feature = "sol-tightening")]
-    log_heuristic_obj_improvement: bool,
-    log_fence: bool,
-    log_routines: usize,
-    log_bound_points: bool,
-    log_cores: bool,
-    log_inpro: bool,
+/// Machine-readable report of a solver run. This is the schema of the `json`
+/// and `csv` output formats, fields that are not available are omitted.
+#[derive(Default, serde::Serialize)]
+struct Report {
+    /// The early termination reason, `null` if solving finished
+    termination: Option<Termination>,
+    /// The discovered non-dominated points
+    pareto_front: Vec<PointReport>,
+    /// The approximation of the Pareto front, if it is incomplete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approximation: Option<ParetoApproximation>,
+    /// The epsilon gap of the approximation, if it is incomplete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    epsilon_gap: Option<isize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oracle_stats: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding_stats: Option<Vec<EncodingStats>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maxpre_stats: Option<serde_json::Value>,
+    /// The verdict of checking the proof, only with `--check-proof`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_check: Option<ProofCheckReport>,
+}
+
+/// The result of checking the proof in the machine-readable report
+#[derive(serde::Serialize)]
+struct ProofCheckReport {
+    accepted: bool,
+    /// The wall-clock time spent checking in seconds
+    check_time: f64,
+}
+
+/// A non-dominated point in the machine-readable report
+#[derive(serde::Serialize)]
+struct PointReport {
+    costs: Vec<isize>,
+    n_sols: usize,
+    /// The solutions as lists of DIMACS literals, only with `--print-solutions`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solutions: Option<Vec<Vec<i32>>>,
+}
+
+/// Writes a JSON value as `key,value` rows. The key is the dot-separated path
+/// to a value, arrays of scalars are written as one space-separated value.
+fn write_csv_rows<W: Write>(out: &mut W, key: &str, value: &serde_json::Value) -> io::Result<()> {
+    use serde_json::Value;
+
+    let is_scalar = |val: &Value| !matches!(val, Value::Array(_) | Value::Object(_));
+    let scalar = |val: &Value| match val {
+        Value::Null => String::new(),
+        Value::String(str) => str.clone(),
+        val => val.to_string(),
+    };
+    let sub_key = |sub: &str| {
+        if key.is_empty() {
+            sub.to_string()
+        } else {
+            format!("{key}.{sub}")
+        }
+    };
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .try_for_each(|(sub, val)| write_csv_rows(out, &sub_key(sub), val)),
+        Value::Array(vals) if vals.iter().all(is_scalar) => {
+            let vals: Vec<_> = vals.iter().map(scalar).collect();
+            writeln!(out, "{key},{}", vals.join(" "))
+        }
+        Value::Array(vals) => vals
+            .iter()
+            .enumerate()
+            .try_for_each(|(idx, val)| write_csv_rows(out, &sub_key(&idx.to_string()), val)),
+        val => writeln!(out, "{key},{}", scalar(val)),
+    }
 }
 
 pub struct CliLogger {
     stdout: BufferWriter,
-    config: LoggerConfig,
+    config: LogFilter,
     routine_stack: Vec<(&'static str, ProcessTime)>,
 }
 
@@ -1,122 +1,31 @@
-use std::{fs, io, thread};
+use std::{fs, io, path::Path, process, thread, time::Instant};
 
+use anyhow::Context;
 use maxpre::{MaxPre, PreproClauses};
 use rustsat::{
-    encodings::{card, pb},
-    instances::{fio, ReindexVars},
-    solvers::{DefaultInitializer, Initialize},
-    types::Assignment,
+    instances::ReindexVars,
+    solvers::Initialize,
+    types::{Assignment, Lit},
 };
 use rustsat_cadical::CaDiCaL;
 use scuttle_core::{
-    self, prepro,
-    types::{Instance, Reindexer},
-    BiOptSat, CoreBoost, InitCertDefaultBlock, InitDefaultBlock, KernelFunctions, KernelOptions,
-    LowerBounding, MaybeTerminatedError, PMinimal, Solve,
+    self,
+    builder::{self, CaDiCaLDefaultInit, CaDiCaLPlainInit, CaDiCaLSatInit, CaDiCaLUnsatInit},
+    checkpoint::Progress,
+    logging::JsonlLogger,
+    portfolio::{Portfolio, PortfolioAlg, PortfolioConfig},
+    prepro,
+    types::{Instance, ParetoFront, Reindexer},
+    MaybeTerminatedError, SolverBuilder, SolverHandle,
 };
 
 mod cli;
-use cli::{Algorithm, CadicalConfig, CardEncoding, Cli, PbEncoding};
+use cli::{Algorithm, CadicalConfig, Cli, OracleBackend};
+mod proof_writer;
 
-/// The SAT solver used
+/// The SAT solver used in portfolio mode
 type Oracle = CaDiCaL<'static, 'static>;
 
-/// P-Minimal instantiation used
-type PMin<OInit = CaDiCaLDefaultInit> =
-    PMinimal<Oracle, pb::DbGte, card::DbTotalizer, io::BufWriter<fs::File>, OInit>;
-/// BiOptSat Instantiation used
-type Bos<PBE, CE, OInit = CaDiCaLDefaultInit> =
-    BiOptSat<Oracle, PBE, CE, io::BufWriter<fs::File>, OInit>;
-/// Lower-bounding instantiation used
-type Lb<OInit = CaDiCaLDefaultInit> =
-    LowerBounding<Oracle, pb::DbGte, card::DbTotalizer, io::BufWriter<fs::File>, OInit>;
-
-// TODO: this macro will potentially need a variant without core boosting
-macro_rules! run {
-    ($slv:ident, $inst:expr, $proof:expr, $prepro:expr, $reindexer:expr, $kernel_opts:expr, $cb_opts:expr, $cli:expr) => {
-        if let Some(proof) = $proof {
-            let mut alg = setup_alg_cert::<$slv>($cli, $inst, $kernel_opts, proof)?;
-            let cont = if let Some(opts) = $cb_opts {
-                handle_termination(alg.core_boost(opts.clone()), $cli)?.unwrap_or(false)
-            } else {
-                true
-            };
-            if cont {
-                handle_termination(alg.solve($cli.limits), $cli)?;
-            };
-            post_solve(alg, $cli, $prepro, $reindexer)?;
-        } else {
-            let mut alg = setup_alg::<$slv>($cli, $inst, $kernel_opts)?;
-            let cont = if let Some(opts) = $cb_opts {
-                handle_termination(alg.core_boost(opts.clone()), $cli)?.unwrap_or(false)
-            } else {
-                true
-            };
-            if cont {
-                handle_termination(alg.solve($cli.limits), $cli)?;
-            };
-            post_solve(alg, $cli, $prepro, $reindexer)?;
-        }
-    };
-}
-
-// TODO: this macro will potentially need a variant without core boosting
-macro_rules! dispatch_options {
-    ($slv:ident, $inst:expr, $proof:expr, $prepro:expr, $reindexer:expr, $kernel_opts:expr, $cb_opts:expr, $cli:expr) => {
-        match $cli.cadical_config {
-            CadicalConfig::Default => run!(
-                $slv,
-                $inst,
-                $proof,
-                $prepro,
-                $reindexer,
-                $kernel_opts,
-                $cb_opts,
-                $cli
-            ),
-            CadicalConfig::Plain => {
-                type Slv = $slv<CaDiCaLPlainInit>;
-                run!(
-                    Slv,
-                    $inst,
-                    $proof,
-                    $prepro,
-                    $reindexer,
-                    $kernel_opts,
-                    $cb_opts,
-                    $cli
-                )
-            }
-            CadicalConfig::Sat => {
-                type Slv = $slv<CaDiCaLSatInit>;
-                run!(
-                    Slv,
-                    $inst,
-                    $proof,
-                    $prepro,
-                    $reindexer,
-                    $kernel_opts,
-                    $cb_opts,
-                    $cli
-                )
-            }
-            CadicalConfig::Unsat => {
-                type Slv = $slv<CaDiCaLUnsatInit>;
-                run!(
-                    Slv,
-                    $inst,
-                    $proof,
-                    $prepro,
-                    $reindexer,
-                    $kernel_opts,
-                    $cb_opts,
-                    $cli
-                )
-            }
-        }
-    };
-}
-
 fn main() -> anyhow::Result<()> {
     let cli = Cli::init();
 
@@ -124,6 +33,9 @@ fn main() -> anyhow::Result<()> {
         Ok(_) => (),
         Err(err) => {
             cli.error(&format!("{err}"))?;
+            if err.downcast_ref::<Error>() == Some(&Error::ProofRejected) {
+                process::exit(1);
+            }
             cli.error(&format!("{}", err.backtrace()))?;
         }
     };
@@ -135,13 +47,68 @@ fn sub_main(cli: &Cli) -> anyhow::Result<()> {
     cli.print_header()?;
     cli.print_solver_config()?;
 
+    if cli.oracle != OracleBackend::Cadical && matches!(cli.alg, Algorithm::Portfolio(..)) {
+        cli.error("portfolio mode only supports the cadical oracle")?;
+        anyhow::bail!(Error::InvalidConfig);
+    }
+
+    if cli.check_proof && cli.proof_paths.is_none() {
+        cli.error("checking the proof requires a proof path")?;
+        anyhow::bail!(Error::InvalidConfig);
+    }
+    if cli.checkpoint_path.is_some() || cli.resume_path.is_some() {
+        if matches!(
+            cli.alg,
+            Algorithm::DivCon(..) | Algorithm::Lexicographic(..) | Algorithm::Portfolio(..)
+        ) {
+            cli.error("checkpoints are only supported for p-minimal, bioptsat and lower-bounding")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+        if cli.resume_path.is_some() && cli.proof_paths.is_some() {
+            cli.error("resuming from a checkpoint is not supported with proof logging")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+    }
+    if cli.warm_start_path.is_some() {
+        if matches!(
+            cli.alg,
+            Algorithm::DivCon(..) | Algorithm::Lexicographic(..) | Algorithm::Portfolio(..)
+        ) {
+            cli.error("warm starts are only supported for p-minimal, bioptsat and lower-bounding")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+        if cli.preprocessing || cli.reindexing {
+            cli.error("warm starts are not supported with preprocessing or reindexing")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+    }
+    if cli.preprocessing && cli.proof_paths.is_some() {
+        cli.error("preprocessing is not supported with proof logging")?;
+        anyhow::bail!(Error::InvalidConfig);
+    }
+    if cli.proof_to_stdout() {
+        if cli.check_proof {
+            cli.error("cannot check a proof that is written to stdout")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+        if cli.machine_readable_output() {
+            cli.error("cannot write both the proof and a machine-readable report to stdout")?;
+            anyhow::bail!(Error::InvalidConfig);
+        }
+    }
+
     cli.info(&format!("solving instance {:?}", cli.inst_path))?;
 
-    let parsed = prepro::parse(cli.inst_path.clone(), cli.file_format, cli.opb_options)?;
+    let parsed = if cli.native_constraints || cli.proof_paths.is_some() {
+        prepro::parse_native(cli.inst_path.clone(), cli.file_format, cli.opb_options)?
+    } else {
+        prepro::parse(cli.inst_path.clone(), cli.file_format, cli.opb_options)?
+    };
 
     // MaxPre Preprocessing
     let (prepro, inst) = if cli.preprocessing {
-        let (prepro, inst) = prepro::max_pre(parsed, &cli.maxpre_techniques, cli.maxpre_reindexing);
+        let (prepro, inst) =
+            prepro::max_pre(parsed, &cli.maxpre_techniques, cli.maxpre_reindexing)?;
         (Some(prepro), inst)
     } else {
         (None, prepro::handle_soft_clauses(parsed))
@@ -149,74 +116,159 @@ fn sub_main(cli: &Cli) -> anyhow::Result<()> {
 
     // Reindexing
     let (inst, reindexer) = if cli.reindexing {
-        let (reind, inst) = prepro::reindexing(inst);
+        let (reind, inst) = prepro::reindexing(inst)?;
         (inst, Some(reind))
     } else {
         (inst, None)
     };
 
-    let proof = if let Some((proof_path, veripb_input_path)) = &cli.proof_paths {
-        // Write constraints out for VeriPB
-        // FIXME: When receiving an OPB input file, we should certify the translation to CNF and
-        // simply strip the objectives for the VeriPB input
+    let (proof, proof_sink) = if let Some((proof_path, veripb_input_path)) = &cli.proof_paths {
+        // Write constraints out for VeriPB. The constraints of OPB inputs are kept native, so that
+        // the proof certifies their encoding and can be checked against the input file without
+        // its objectives. Reindexing renames the variables, and DIMACS inputs are not OPB, so
+        // the constraints of the instance are written instead.
         let mut writer = io::BufWriter::new(fs::File::create(veripb_input_path)?);
-        let iter = inst
-            .iter_clauses()
-            .map(|cl| fio::opb::FileLine::<Option<_>>::Clause(cl.clone()));
-        fio::opb::write_opb_lines(&mut writer, iter, fio::opb::Options::default())?;
-        // Initialize proof
-        Some(pidgeons::Proof::new_with_conclusion(
-            io::BufWriter::new(fs::File::create(proof_path)?),
-            inst.n_clauses(),
+        if !cli.reindexing && cli.file_format.resolve(&cli.inst_path)? == prepro::FileFormat::Opb {
+            prepro::write_opb_veripb_input(&cli.inst_path, cli.file_format, &mut writer)?;
+        } else {
+            inst.write_veripb_input(&mut writer)?;
+        }
+        let (proof_writer, proof_sink) = proof_writer::open(proof_path)?;
+        // Initialize proof without claim. The conclusion is updated by the solver with the result
+        // of solving, it is unsatisfiability only if the entire Pareto front is found.
+        let proof = pidgeons::Proof::new_with_conclusion(
+            proof_writer,
+            inst.n_constraints(),
             false,
             pidgeons::OutputGuarantee::None,
-            &pidgeons::Conclusion::<&str>::Unsat(Some(pidgeons::ConstraintId::last(1))),
-        )?)
+            &pidgeons::Conclusion::<&str>::None,
+        )?;
+        (Some(proof), Some(proof_sink))
     } else {
-        None
+        (None, None)
     };
 
-    match cli.alg {
-        Algorithm::PMinimal(opts, ref cb_opts) => {
-            dispatch_options!(PMin, inst, proof, prepro, reindexer, opts, cb_opts, cli)
-        }
-        Algorithm::BiOptSat(opts, pb_enc, card_enc, ref cb_opts) => {
-            if inst.n_objs() != 2 {
-                cli.error("the bioptsat algorithm can only be run on bi-objective problems")?;
-                anyhow::bail!(Error::InvalidInstance);
-            }
-            if cb_opts.is_some() && (pb_enc != PbEncoding::Gte || card_enc != CardEncoding::Tot) {
-                cli.error("core boosting is only implemented for the GTE and Totalizer encodings")?;
+    let (alg, opts, (pb_enc, card_enc), cb_opts) = match &cli.alg {
+        Algorithm::PMinimal(opts, pb_enc, card_enc, cb_opts) => (
+            builder::Algorithm::PMinimal,
+            *opts,
+            (*pb_enc, *card_enc),
+            cb_opts,
+        ),
+        Algorithm::BiOptSat(opts, pb_enc, card_enc, cb_opts) => (
+            builder::Algorithm::BiOptSat,
+            *opts,
+            (*pb_enc, *card_enc),
+            cb_opts,
+        ),
+        Algorithm::LowerBounding(opts, pb_enc, card_enc, cb_opts) => (
+            builder::Algorithm::LowerBounding,
+            *opts,
+            (*pb_enc, *card_enc),
+            cb_opts,
+        ),
+        Algorithm::DivCon(opts, dc_opts, cb_opts) => (
+            builder::Algorithm::DivCon(*dc_opts),
+            *opts,
+            Default::default(),
+            cb_opts,
+        ),
+        Algorithm::Lexicographic(opts, lex_opts, cb_opts) => (
+            builder::Algorithm::Lexicographic(lex_opts.clone()),
+            *opts,
+            Default::default(),
+            cb_opts,
+        ),
+        Algorithm::Portfolio(opts, algs, cooperative, cb_opts) => {
+            if proof.is_some() {
+                cli.error("proof logging is not supported in portfolio mode")?;
                 anyhow::bail!(Error::InvalidConfig);
             }
-            match pb_enc {
-                PbEncoding::Gte => match card_enc {
-                    CardEncoding::Tot => {
-                        type BosEnc<OInit = DefaultInitializer> =
-                            Bos<pb::DbGte, card::DbTotalizer, OInit>;
-                        dispatch_options!(
-                            BosEnc, inst, proof, prepro, reindexer, opts, cb_opts, cli
-                        )
-                    }
-                },
+            if cli.trace_path.is_some() {
+                cli.warning("event traces are not written in portfolio mode")?;
             }
+            let mut algs = algs.clone();
+            if inst.n_objs() != 2 && algs.contains(&PortfolioAlg::BiOptSat) {
+                cli.warning("skipping bioptsat in portfolio on a non bi-objective instance")?;
+                algs.retain(|&alg| alg != PortfolioAlg::BiOptSat);
+            }
+            if algs.is_empty() {
+                cli.error("portfolio does not contain any algorithm to run")?;
+                anyhow::bail!(Error::InvalidConfig);
+            }
+            let configs = algs
+                .into_iter()
+                .map(|alg| PortfolioConfig {
+                    alg,
+                    opts: *opts,
+                    cb: cb_opts.clone(),
+                })
+                .collect();
+            portfolio_main(cli, inst, configs, *cooperative, prepro, reindexer)?;
+            cli.print_report()?;
+            return Ok(());
         }
-        Algorithm::LowerBounding(opts, ref cb_opts) => {
-            dispatch_options!(Lb, inst, proof, prepro, reindexer, opts, cb_opts, cli)
-        }
+    };
+
+    let mut builder = SolverBuilder::new(alg);
+    builder
+        .set_kernel_options(opts)
+        .set_core_boosting(cb_opts.clone())
+        .set_encodings(pb_enc, card_enc)
+        .set_oracle(cli.oracle)
+        .set_cadical_config(cli.cadical_config)
+        .set_proof(proof);
+    let mut solver = builder.build(inst)?;
+    setup_solver(solver.as_mut(), cli)?;
+    let resumed = if let Some(path) = &cli.resume_path {
+        solver.restore_progress(Progress::read(path)?)?;
+        cli.info(&format!(
+            "resumed solving from checkpoint {}",
+            path.display()
+        ))?;
+        true
+    } else {
+        false
+    };
+    if let Some(path) = &cli.warm_start_path {
+        let costs = solver.warm_start(read_warm_start(path)?)?;
+        cli.info(&format!(
+            "loaded {} warm-start solutions from {}",
+            costs.len(),
+            path.display()
+        ))?;
+    }
+    // Core boosting is already reflected in the progress restored from a checkpoint
+    if resumed || handle_termination(solver.core_boost(cli.limits), cli)?.unwrap_or(false) {
+        handle_termination(solver.solve(cli.limits), cli)?;
+    }
+    if let Some(path) = &cli.checkpoint_path {
+        solver.progress()?.write(path)?;
+        cli.info(&format!("wrote checkpoint to {}", path.display()))?;
+    }
+    post_solve(solver, cli, prepro, reindexer)?;
+
+    // The solver has been dropped after solving, which concludes the proof
+    if let Some(proof_sink) = proof_sink {
+        proof_sink
+            .finish()
+            .context("failed to finish writing the proof")?;
+    }
+    if cli.check_proof {
+        check_proof(cli)?;
+    }
+    cli.print_report()?;
+    if cli.proof_rejected() {
+        anyhow::bail!(Error::ProofRejected);
     }
     Ok(())
 }
 
-fn setup_alg<Alg>(cli: &Cli, inst: Instance, opts: KernelOptions) -> anyhow::Result<Alg>
-where
-    Alg: InitDefaultBlock + KernelFunctions,
-{
-    let mut alg = Alg::from_instance_default_blocking(inst, opts)?;
-
-    // === Set up CLI interaction ===
+/// Sets up the CLI interaction with a solver: signal handling, the CLI logger and, if requested,
+/// the event trace logger
+fn setup_solver(solver: &mut dyn SolverHandle, cli: &Cli) -> anyhow::Result<()> {
     // Set up signal handling
-    let mut interrupter = alg.interrupter();
+    let mut interrupter = solver.interrupter();
     let mut signals = signal_hook::iterator::Signals::new([
         signal_hook::consts::SIGTERM,
         signal_hook::consts::SIGINT,
@@ -230,25 +282,148 @@ where
         }
     });
 
-    alg.attach_logger(cli.new_cli_logger());
+    solver.attach_logger(Box::new(cli.new_cli_logger()));
+    if let Some(path) = &cli.trace_path {
+        solver.attach_logger(Box::new(JsonlLogger::create(path)?));
+    }
+    Ok(())
+}
+
+/// Reads known solutions from a file. The file is either the JSON report of a previous run with
+/// `--print-solutions`, or holds one solution per line starting with `v`, given as DIMACS
+/// literals.
+fn read_warm_start(path: &Path) -> anyhow::Result<Vec<Assignment>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read warm-start file {}", path.display()))?;
+    let solutions: Vec<Vec<i32>> = if content.trim_start().starts_with('{') {
+        let report: serde_json::Value = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse warm-start file {}", path.display()))?;
+        report["pareto_front"]
+            .as_array()
+            .context("the warm-start report does not contain a Pareto front")?
+            .iter()
+            .filter_map(|point| point["solutions"].as_array())
+            .flatten()
+            .map(|sol| serde_json::from_value(sol.clone()))
+            .collect::<Result<_, _>>()?
+    } else {
+        content
+            .lines()
+            .filter_map(|line| line.strip_prefix("v "))
+            .map(|line| {
+                line.split_whitespace()
+                    .map(str::parse::<i32>)
+                    .filter(|lit| lit != &Ok(0))
+                    .collect::<Result<_, _>>()
+            })
+            .collect::<Result<_, _>>()?
+    };
+    solutions
+        .into_iter()
+        .map(|lits| {
+            let mut sol = Assignment::default();
+            for lit in lits {
+                sol.assign_lit(Lit::from_ipasir(lit)?);
+            }
+            Ok(sol)
+        })
+        .collect()
+}
+
+fn post_solve(
+    alg: Box<dyn SolverHandle>,
+    cli: &Cli,
+    mut prepro: Option<MaxPre>,
+    reindexer: Option<Reindexer>,
+) -> anyhow::Result<()> {
+    let pareto_front = reconstruct(alg.pareto_front(), &mut prepro, reindexer);
+    let approx = alg.approximation();
+
+    cli.print_pareto_front(pareto_front)?;
+    cli.print_approximation(&approx)?;
+
+    let (stats, ostats, estats) = alg.all_stats();
+    cli.print_stats(stats)?;
+    // Get extended stats for solver that supports stats
+    if let Some(stats) = ostats {
+        cli.print_oracle_stats(stats)?;
+    }
+    if let Some(stats) = estats {
+        cli.print_encoding_stats(stats)?;
+    }
+    if let Some(prepro) = prepro {
+        cli.print_maxpre_stats(prepro.stats())?;
+    }
+
+    Ok(())
+}
+
+/// Checks the written proof with a locally installed VeriPB
+fn check_proof(cli: &Cli) -> anyhow::Result<()> {
+    let Some((proof_path, veripb_input_path)) = &cli.proof_paths else {
+        return Ok(());
+    };
+    cli.info(&format!("checking proof {proof_path:?}"))?;
+    let start = Instant::now();
+    let out = process::Command::new("veripb")
+        .arg("--forceCheckDeletion")
+        .arg(veripb_input_path)
+        .arg(proof_path)
+        .output()
+        .context("failed to run veripb, is it installed?")?;
+    let check_time = start.elapsed();
+    if !out.status.success() {
+        cli.error(&format!(
+            "veripb rejected the proof:\n{}{}",
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        ))?;
+    }
+    cli.print_proof_check(out.status.success(), check_time)?;
+    Ok(())
+}
 
-    Ok(alg)
+/// Runs the portfolio with the configured CaDiCaL initializer
+fn portfolio_main(
+    cli: &Cli,
+    inst: Instance,
+    configs: Vec<PortfolioConfig>,
+    cooperative: bool,
+    prepro: Option<MaxPre>,
+    reindexer: Option<Reindexer>,
+) -> anyhow::Result<()> {
+    match cli.cadical_config {
+        CadicalConfig::Default => {
+            run_portfolio::<CaDiCaLDefaultInit>(cli, inst, configs, cooperative, prepro, reindexer)
+        }
+        CadicalConfig::Plain => {
+            run_portfolio::<CaDiCaLPlainInit>(cli, inst, configs, cooperative, prepro, reindexer)
+        }
+        CadicalConfig::Sat => {
+            run_portfolio::<CaDiCaLSatInit>(cli, inst, configs, cooperative, prepro, reindexer)
+        }
+        CadicalConfig::Unsat => {
+            run_portfolio::<CaDiCaLUnsatInit>(cli, inst, configs, cooperative, prepro, reindexer)
+        }
+    }
 }
 
-fn setup_alg_cert<Alg>(
+fn run_portfolio<OInit>(
     cli: &Cli,
     inst: Instance,
-    opts: KernelOptions,
-    proof: pidgeons::Proof<Alg::ProofWriter>,
-) -> anyhow::Result<Alg>
+    configs: Vec<PortfolioConfig>,
+    cooperative: bool,
+    mut prepro: Option<MaxPre>,
+    reindexer: Option<Reindexer>,
+) -> anyhow::Result<()>
 where
-    Alg: InitCertDefaultBlock + KernelFunctions,
+    OInit: Initialize<Oracle>,
 {
-    let mut alg = Alg::from_instance_default_blocking_cert(inst, opts, proof)?;
+    let mut portfolio = Portfolio::<OInit>::new(configs);
+    portfolio.set_cooperative(cooperative);
 
-    // === Set up CLI interaction ===
     // Set up signal handling
-    let mut interrupter = alg.interrupter();
+    let interrupter = portfolio.interrupter();
     let mut signals = signal_hook::iterator::Signals::new([
         signal_hook::consts::SIGTERM,
         signal_hook::consts::SIGINT,
@@ -262,22 +437,35 @@ where
         }
     });
 
-    alg.attach_logger(cli.new_cli_logger());
+    let res = portfolio.solve(inst, cli.limits)?;
+
+    if let Some(winner) = res.winner {
+        cli.info(&format!(
+            "portfolio won by configuration {winner} ({})",
+            portfolio.configs()[winner].alg
+        ))?;
+    } else {
+        cli.warning("all portfolio runs terminated early, merging partial Pareto fronts")?;
+    }
+
+    cli.print_pareto_front(reconstruct(res.pareto_front, &mut prepro, reindexer))?;
 
-    Ok(alg)
+    if let Some(winner) = res.winner {
+        cli.print_stats(res.stats[winner])?;
+    }
+    if let Some(prepro) = prepro {
+        cli.print_maxpre_stats(prepro.stats())?;
+    }
+
+    Ok(())
 }
 
-fn post_solve<Alg>(
-    alg: Alg,
-    cli: &Cli,
-    mut prepro: Option<MaxPre>,
+/// Maps the solutions of a Pareto front back to the original instance
+fn reconstruct(
+    pareto_front: ParetoFront,
+    prepro: &mut Option<MaxPre>,
     reindexer: Option<Reindexer>,
-) -> io::Result<()>
-where
-    Alg: Solve,
-{
-    let pareto_front = alg.pareto_front();
-
+) -> ParetoFront {
     // Reverse reindexing
     let pareto_front = if let Some(reindexer) = reindexer {
         let reverse = |l| reindexer.reverse_lit(l);
@@ -290,28 +478,11 @@ where
     };
 
     // Solution reconstruction
-    let pareto_front = if let Some(ref mut prepro) = prepro {
+    if let Some(prepro) = prepro {
         pareto_front.convert_solutions(&mut |s| prepro.reconstruct(s))
     } else {
         pareto_front
-    };
-
-    cli.print_pareto_front(pareto_front)?;
-
-    let (stats, ostats, estats) = alg.all_stats();
-    cli.print_stats(stats)?;
-    // Get extended stats for solver that supports stats
-    if let Some(stats) = ostats {
-        cli.print_oracle_stats(stats)?;
     }
-    if let Some(stats) = estats {
-        cli.print_encoding_stats(stats)?;
-    }
-    if let Some(prepro) = prepro {
-        cli.print_maxpre_stats(prepro.stats())?;
-    }
-
-    Ok(())
 }
 
 fn handle_termination<T>(ret: MaybeTerminatedError<T>, cli: &Cli) -> anyhow::Result<Option<T>> {
@@ -327,62 +498,8 @@ fn handle_termination<T>(ret: MaybeTerminatedError<T>, cli: &Cli) -> anyhow::Res
 
 #[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
 enum Error {
-    #[error("Invalid instance")]
-    InvalidInstance,
     #[error("Invalid configuration")]
     InvalidConfig,
-}
-
-struct CaDiCaLDefaultInit;
-
-impl Initialize<CaDiCaL<'static, 'static>> for CaDiCaLDefaultInit {
-    fn init() -> CaDiCaL<'static, 'static> {
-        let mut slv = CaDiCaL::default();
-        // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
-        // It might also be bad for core-guided search performance
-        slv.set_option("ilb", 0).unwrap();
-        slv
-    }
-}
-
-struct CaDiCaLPlainInit;
-
-impl Initialize<CaDiCaL<'static, 'static>> for CaDiCaLPlainInit {
-    fn init() -> CaDiCaL<'static, 'static> {
-        let mut slv = CaDiCaL::default();
-        slv.set_configuration(rustsat_cadical::Config::Plain)
-            .expect("failed to set cadical config");
-        // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
-        // It might also be bad for core-guided search performance
-        slv.set_option("ilb", 0).unwrap();
-        slv
-    }
-}
-
-struct CaDiCaLSatInit;
-
-impl Initialize<CaDiCaL<'static, 'static>> for CaDiCaLSatInit {
-    fn init() -> CaDiCaL<'static, 'static> {
-        let mut slv = CaDiCaL::default();
-        slv.set_configuration(rustsat_cadical::Config::Sat)
-            .expect("failed to set cadical config");
-        // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
-        // It might also be bad for core-guided search performance
-        slv.set_option("ilb", 0).unwrap();
-        slv
-    }
-}
-
-struct CaDiCaLUnsatInit;
-
-impl Initialize<CaDiCaL<'static, 'static>> for CaDiCaLUnsatInit {
-    fn init() -> CaDiCaL<'static, 'static> {
-        let mut slv = CaDiCaL::default();
-        slv.set_configuration(rustsat_cadical::Config::Unsat)
-            .expect("failed to set cadical config");
-        // NOTE: ILB apparently causes CaDiCaL to diverge between proof logging or not?
-        // It might also be bad for core-guided search performance
-        slv.set_option("ilb", 0).unwrap();
-        slv
-    }
+    #[error("Proof rejected by VeriPB")]
+    ProofRejected,
 }
@@ -0,0 +1,203 @@
+//! # Proof Output
+//!
+//! The VeriPB proof is written by the proof tracer of the oracle, which only
+//! releases its writer when the solver is dropped. Compressed proofs must be
+//! finished after that, so the actual output is shared between the writer
+//! passed to the solver and a [`ProofSink`] kept by the CLI.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use scuttle_core::builder::ProofWriter;
+
+/// Opens the writer for the VeriPB proof. A path of `-` writes the proof to stdout, while the
+/// extensions `.gz`, `.xz` and `.zst` compress the proof on the fly. The returned sink must be
+/// finished once the solver holding the writer is dropped.
+pub fn open(path: &Path) -> io::Result<(ProofWriter, ProofSink)> {
+    let output = if path == Path::new("-") {
+        Output::Stdout(io::stdout())
+    } else {
+        let file = fs::File::create(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gz") => Output::Gz(flate2::write::GzEncoder::new(
+                file,
+                flate2::Compression::default(),
+            )),
+            Some("xz") => Output::Xz(xz2::write::XzEncoder::new(file, 6)),
+            Some("zst") => Output::Zstd(zstd::Encoder::new(file, 0)?),
+            _ => Output::File(file),
+        }
+    };
+    let sink = ProofSink(Arc::new(Mutex::new(Shared {
+        output: Some(output),
+        error: None,
+    })));
+    Ok((io::BufWriter::new(Box::new(sink.clone())), sink))
+}
+
+/// The output of the proof, shared with the writer passed to the solver
+#[derive(Clone)]
+pub struct ProofSink(Arc<Mutex<Shared>>);
+
+struct Shared {
+    /// The output, [`None`] once finished
+    output: Option<Output>,
+    /// The first error that occurred while writing. Errors while flushing the
+    /// writer when the solver is dropped cannot be returned to the solver, so
+    /// they are reported when finishing.
+    error: Option<io::Error>,
+}
+
+enum Output {
+    Stdout(io::Stdout),
+    File(fs::File),
+    Gz(flate2::write::GzEncoder<fs::File>),
+    Xz(xz2::write::XzEncoder<fs::File>),
+    Zstd(zstd::Encoder<'static, fs::File>),
+}
+
+impl ProofSink {
+    fn lock(&self) -> MutexGuard<'_, Shared> {
+        self.0.lock().expect("proof writer lock poisoned")
+    }
+
+    /// Finishes the compression and flushes the output. Returns the first error that occurred
+    /// while writing the proof.
+    pub fn finish(&self) -> io::Result<()> {
+        let mut shared = self.lock();
+        if let Some(err) = shared.error.take() {
+            return Err(err);
+        }
+        match shared.output.take() {
+            Some(Output::Stdout(mut out)) => out.flush(),
+            Some(Output::File(mut file)) => file.flush(),
+            Some(Output::Gz(enc)) => enc.finish()?.flush(),
+            Some(Output::Xz(enc)) => enc.finish()?.flush(),
+            Some(Output::Zstd(enc)) => enc.finish()?.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Shared {
+    /// Records the first error and passes it on
+    fn record(&mut self, err: io::Error) -> io::Error {
+        if self.error.is_none() {
+            self.error = Some(io::Error::new(err.kind(), err.to_string()));
+        }
+        err
+    }
+
+    fn writer(&mut self) -> io::Result<&mut dyn Write> {
+        match &mut self.output {
+            Some(Output::Stdout(out)) => Ok(out),
+            Some(Output::File(file)) => Ok(file),
+            Some(Output::Gz(enc)) => Ok(enc),
+            Some(Output::Xz(enc)) => Ok(enc),
+            Some(Output::Zstd(enc)) => Ok(enc),
+            None => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "the proof output is already finished",
+            )),
+        }
+    }
+}
+
+impl Write for ProofSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut shared = self.lock();
+        match shared.writer().and_then(|writer| writer.write(buf)) {
+            Ok(n) => Ok(n),
+            Err(err) => Err(shared.record(err)),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut shared = self.lock();
+        match shared.writer().and_then(|writer| writer.flush()) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(shared.record(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{self, Read, Write},
+        path::Path,
+    };
+
+    const CONTENT: &[u8] = b"pseudo-Boolean proof version 2.0\nf 3\n";
+
+    /// Writes the content through the proof writer for the given file name and returns the
+    /// bytes stored in the file
+    fn written(name: &str) -> Vec<u8> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(name);
+        let (mut writer, sink) = super::open(&path).unwrap();
+        writer.write_all(CONTENT).unwrap();
+        drop(writer);
+        sink.finish().unwrap();
+        fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(written("proof.pbp"), CONTENT);
+    }
+
+    #[test]
+    fn gz() {
+        let mut content = vec![];
+        flate2::read::GzDecoder::new(&written("proof.pbp.gz")[..])
+            .read_to_end(&mut content)
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+
+    #[test]
+    fn xz() {
+        let mut content = vec![];
+        xz2::read::XzDecoder::new(&written("proof.pbp.xz")[..])
+            .read_to_end(&mut content)
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+
+    #[test]
+    fn zst() {
+        assert_eq!(
+            zstd::decode_all(&written("proof.pbp.zst")[..]).unwrap(),
+            CONTENT
+        );
+    }
+
+    #[test]
+    fn stdout() {
+        let (mut writer, sink) = super::open(Path::new("-")).unwrap();
+        writer.write_all(CONTENT).unwrap();
+        drop(writer);
+        sink.finish().unwrap();
+        // the proof is not written to a file named `-`
+        assert!(!Path::new("-").exists());
+    }
+
+    #[test]
+    fn write_after_finish() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut writer, sink) = super::open(&dir.path().join("proof.pbp.gz")).unwrap();
+        sink.finish().unwrap();
+        writer.write_all(CONTENT).unwrap();
+        let err = writer.flush().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        // the error is reported when finishing
+        drop(writer);
+        assert!(sink.finish().is_err());
+    }
+}